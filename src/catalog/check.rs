@@ -0,0 +1,30 @@
+use super::{embedded, loader, validate};
+use std::path::Path;
+
+pub fn check(root: &Path) -> Result<usize, Vec<String>> {
+    if loader::should_use_embedded(root) {
+        let harnesses = embedded::load().map_err(|error| vec![error.to_string()])?;
+        return finish(harnesses.len(), validate(&harnesses));
+    }
+    let dirs = loader::dirs(root).map_err(|error| vec![format!("{}: {error}", root.display())])?;
+    let mut harnesses = Vec::new();
+    let mut problems = Vec::new();
+    for dir in dirs.iter().filter(|dir| dir.is_dir()) {
+        match loader::load_harness(dir) {
+            Ok(harness) => harnesses.push(harness),
+            Err(error) => problems.push(format!("{}: {error}", dir.display())),
+        }
+    }
+    if problems.is_empty() || !harnesses.is_empty() {
+        problems.extend(validate(&harnesses));
+    }
+    finish(harnesses.len(), problems)
+}
+
+fn finish(count: usize, problems: Vec<String>) -> Result<usize, Vec<String>> {
+    if problems.is_empty() {
+        Ok(count)
+    } else {
+        Err(problems)
+    }
+}
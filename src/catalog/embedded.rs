@@ -33,6 +33,8 @@ fn load_harness(files: &BTreeMap<&str, &str>) -> io::Result<Harness> {
         env_mode: EnvMode::parse(&parser::string(&meta, "env_mode").map_err(invalid)?)
             .map_err(invalid)?,
         env: parser::list(&meta, "env").map_err(invalid)?,
+        full_screen: parser::flag(&meta, "full_screen").map_err(invalid)?,
+        project_args: parser::list(&meta, "project_args").map_err(invalid)?,
         capabilities,
     })
 }
@@ -47,7 +49,9 @@ fn load_capability(
     Ok(CapabilityPlan {
         capability,
         summary: parser::string(&data, "summary").map_err(invalid)?,
-        command: CommandPlan::new(command, parser::list(&data, "args").map_err(invalid)?),
+        command: CommandPlan::new(command, parser::list(&data, "args").map_err(invalid)?)
+            .with_requires(parser::list(&data, "requires").map_err(invalid)?)
+            .with_default_args(parser::list(&data, "default_args").map_err(invalid)?),
     })
 }
 
@@ -22,7 +22,7 @@ pub fn load(root: &Path) -> io::Result<Vec<Harness>> {
     Ok(harnesses)
 }
 
-fn should_use_embedded(root: &Path) -> bool {
+pub(super) fn should_use_embedded(root: &Path) -> bool {
     !catalog_env_set() && root == Path::new("harnesses") && !root.is_dir()
 }
 
@@ -30,7 +30,7 @@ fn catalog_env_set() -> bool {
     env::var_os("TERMINAL_JARVIS_CATALOG").is_some_and(|value| !value.is_empty())
 }
 
-fn load_harness(dir: &Path) -> io::Result<Harness> {
+pub(super) fn load_harness(dir: &Path) -> io::Result<Harness> {
     let meta = fields(&dir.join("index.toml"))?;
     let mut capabilities = Vec::new();
     for capability in Capability::ALL {
@@ -44,6 +44,8 @@ fn load_harness(dir: &Path) -> io::Result<Harness> {
         env_mode: EnvMode::parse(&parser::string(&meta, "env_mode").map_err(invalid)?)
             .map_err(invalid)?,
         env: parser::list(&meta, "env").map_err(invalid)?,
+        full_screen: parser::flag(&meta, "full_screen").map_err(invalid)?,
+        project_args: parser::list(&meta, "project_args").map_err(invalid)?,
         capabilities,
     })
 }
@@ -55,7 +57,9 @@ fn load_capability(dir: &Path, capability: Capability) -> io::Result<CapabilityP
     Ok(CapabilityPlan {
         capability,
         summary: parser::string(&data, "summary").map_err(invalid)?,
-        command: CommandPlan::new(command, parser::list(&data, "args").map_err(invalid)?),
+        command: CommandPlan::new(command, parser::list(&data, "args").map_err(invalid)?)
+            .with_requires(parser::list(&data, "requires").map_err(invalid)?)
+            .with_default_args(parser::list(&data, "default_args").map_err(invalid)?),
     })
 }
 
@@ -64,7 +68,7 @@ fn fields(path: &Path) -> io::Result<Fields> {
     parser::parse(&data).map_err(invalid)
 }
 
-fn dirs(root: &Path) -> io::Result<Vec<PathBuf>> {
+pub(super) fn dirs(root: &Path) -> io::Result<Vec<PathBuf>> {
     let mut dirs = fs::read_dir(root)?
         .map(|entry| entry.map(|entry| entry.path()))
         .collect::<io::Result<Vec<_>>>()?;
@@ -1,7 +1,9 @@
+mod check;
 mod embedded;
 mod loader;
 pub(crate) mod parser;
 mod validate;
 
+pub use check::check;
 pub use loader::load;
 pub use validate::validate;
@@ -37,6 +37,14 @@ pub fn list(fields: &Fields, key: &str) -> Result<Vec<String>, String> {
     split_list(inner)
 }
 
+pub fn flag(fields: &Fields, key: &str) -> Result<bool, String> {
+    match fields.get(key).map(|value| value.trim()) {
+        None | Some("false") => Ok(false),
+        Some("true") => Ok(true),
+        Some(other) => Err(format!("'{key}' must be true or false, got {other}")),
+    }
+}
+
 fn split_list(input: &str) -> Result<Vec<String>, String> {
     let mut values = Vec::new();
     let mut current = String::new();
@@ -70,19 +78,5 @@ fn strip_quotes(value: &str) -> Result<String, String> {
 }
 
 #[cfg(test)]
-mod tests {
-    use super::{list, Fields};
-
-    #[test]
-    fn list_preserves_commas_inside_quoted_values() {
-        let mut fields = Fields::new();
-        fields.insert(
-            "args".to_string(),
-            "[\"--scanners\", \"vuln,secret,misconfig\"]".to_string(),
-        );
-        assert_eq!(
-            list(&fields, "args").unwrap(),
-            ["--scanners", "vuln,secret,misconfig"]
-        );
-    }
-}
+#[path = "parser_test.rs"]
+mod tests;
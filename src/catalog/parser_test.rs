@@ -0,0 +1,24 @@
+use super::{flag, list, Fields};
+
+#[test]
+fn list_preserves_commas_inside_quoted_values() {
+    let mut fields = Fields::new();
+    fields.insert(
+        "args".to_string(),
+        "[\"--scanners\", \"vuln,secret,misconfig\"]".to_string(),
+    );
+    assert_eq!(
+        list(&fields, "args").unwrap(),
+        ["--scanners", "vuln,secret,misconfig"]
+    );
+}
+
+#[test]
+fn flag_defaults_to_false_and_rejects_other_values() {
+    let mut fields = Fields::new();
+    assert_eq!(flag(&fields, "full_screen"), Ok(false));
+    fields.insert("full_screen".to_string(), "true".to_string());
+    assert_eq!(flag(&fields, "full_screen"), Ok(true));
+    fields.insert("full_screen".to_string(), "\"yes\"".to_string());
+    assert!(flag(&fields, "full_screen").is_err());
+}
@@ -1,6 +1,8 @@
 use crate::contracts::{Capability, EnvMode, Harness};
 use std::collections::BTreeSet;
 
+const KNOWN_OS: [&str; 3] = ["linux", "macos", "windows"];
+
 pub fn validate(harnesses: &[Harness]) -> Vec<String> {
     let mut errors = Vec::new();
     let mut names = BTreeSet::new();
@@ -22,6 +24,7 @@ pub fn validate(harnesses: &[Harness]) -> Vec<String> {
             errors.push(format!("{} is missing a core capability", harness.name));
         }
         validate_plans(harness, &mut errors);
+        validate_project_args(harness, &mut errors);
     }
     errors
 }
@@ -34,6 +37,16 @@ fn validate_plans(harness: &Harness, errors: &mut Vec<String>) {
                 harness.name, plan.capability
             ));
         }
+        let invalid = plan.command.requires.iter().find(|name| {
+            let (binary, os) = name.split_once('@').unwrap_or((name, "linux"));
+            binary.trim().is_empty() || !KNOWN_OS.contains(&os)
+        });
+        if let Some(name) = invalid {
+            errors.push(format!(
+                "{}:{} has an invalid requirement '{name}'",
+                harness.name, plan.capability
+            ));
+        }
         if plan.capability == Capability::Update && has_interactive_word(&plan.command.render()) {
             errors.push(format!("{} update command looks interactive", harness.name));
         }
@@ -43,6 +56,20 @@ fn validate_plans(harness: &Harness, errors: &mut Vec<String>) {
     }
 }
 
+fn validate_project_args(harness: &Harness, errors: &mut Vec<String>) {
+    let yolo = harness
+        .plan(Capability::Yolo)
+        .map(|plan| &plan.command.args);
+    for flag in &harness.project_args {
+        if !flag.starts_with('-') || yolo.is_some_and(|args| args.contains(flag)) {
+            errors.push(format!(
+                "{} project_args cannot include {flag}",
+                harness.name
+            ));
+        }
+    }
+}
+
 fn validate_env(harness: &str, names: &[String], errors: &mut Vec<String>) {
     for name in names {
         if !name
@@ -3,14 +3,18 @@ use crate::contracts::Capability;
 #[derive(Debug, Eq, PartialEq)]
 pub enum Action {
     Help,
-    List,
+    List(Vec<String>),
+    Search(Vec<String>),
     Check,
     Current,
     Version {
         verbose: bool,
     },
     Use(String),
-    Show(String),
+    Show {
+        name: String,
+        format: ShowFormat,
+    },
     Plan {
         harness: Option<String>,
         capability: Capability,
@@ -21,15 +25,40 @@ pub enum Action {
         extra: Vec<String>,
     },
     Install(String),
+    InstallPreset(Vec<String>),
     SelfUpdate {
         dry_run: bool,
     },
     Update(Option<String>),
+    UpdateOutdated,
+    Outdated(Vec<String>),
+    History(Vec<String>),
     Auth(Vec<String>),
     Config(Vec<String>),
     Cache(Vec<String>),
     Security(Vec<String>),
     Gate(Vec<String>),
     Experimental(Vec<String>),
+    Completions(Vec<String>),
     Legacy(String),
 }
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ShowFormat {
+    Text,
+    Json,
+    Markdown,
+}
+
+impl ShowFormat {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "text" => Ok(ShowFormat::Text),
+            "json" => Ok(ShowFormat::Json),
+            "markdown" | "md" => Ok(ShowFormat::Markdown),
+            other => Err(format!(
+                "unknown format '{other}'; expected text, json, or markdown"
+            )),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-pub use super::action::Action;
+pub use super::action::{Action, ShowFormat};
 use crate::contracts::Capability;
 #[rustfmt::skip]
 fn hlp(words: &[String]) -> bool { words.iter().skip(1).any(|w| w == "--help" || w == "-h") }
@@ -16,8 +16,10 @@ where I: IntoIterator, I::Item: Into<String>,
         "--version" | "-v" => Err(format!("unexpected argument '{}' after --version/-v flag", words[1])),
         "--info" if words.len() == 1 => Ok(Action::Version { verbose: true }),
         "--info" => Err(format!("unexpected argument '{}' after --info flag", words[1])),
-        "list" | "tools" if hlp(&words) => Ok(Action::Help),
-        "list" | "tools" => Ok(Action::List),
+        "list" | "tools" | "search" if hlp(&words) => Ok(Action::Help),
+        "tools" if words.get(1).is_some_and(|w| w == "search") => Ok(Action::Search(words[2..].to_vec())),
+        "search" => Ok(Action::Search(words[1..].to_vec())),
+        "list" | "tools" => Ok(Action::List(words[1..].to_vec())),
         "check" | "status" if hlp(&words) => Ok(Action::Help),
         "check" | "status" => Ok(Action::Check),
         "current" if hlp(&words) => Ok(Action::Help),
@@ -25,32 +27,34 @@ where I: IntoIterator, I::Item: Into<String>,
         "use" if hlp(&words) => Ok(Action::Help),
         "use" => one(&words, "use").map(Action::Use),
         "show" | "info" if hlp(&words) => Ok(Action::Help),
-        "show" | "info" => one(&words, words[0].as_str()).map(Action::Show),
+        "show" | "info" => show(&words),
         "plan" if hlp(&words) => Ok(Action::Help),
         "plan" => plan(&words[1..]),
         "run" if words.get(1).is_some_and(|w| w == "--help" || w == "-h") => Ok(Action::Help),
         "run" => Ok(Action::Run(words[1..].to_vec())),
         "install" if hlp(&words) => Ok(Action::Help),
+        "install" if words.get(1).is_some_and(|w| w == "--preset") => {
+            Ok(Action::InstallPreset(words[2..].to_vec()))
+        }
         "install" => one(&words, "install").map(Action::Install),
         "update" if hlp(&words) => Ok(Action::Help),
+        "update" if words.len() == 2 && words[1] == "--only-outdated" => Ok(Action::UpdateOutdated),
+        "update" if words.get(1).is_some_and(|w| w == "check") => Ok(Action::Outdated(words[2..].to_vec())),
         "update" => optional_one(&words, "update").map(Action::Update),
-        "--update" if words.len() == 1 => Ok(Action::SelfUpdate { dry_run: false }),
-        "--update" if words.len() == 2 && words[1] == "--dry-run" => {
-            Ok(Action::SelfUpdate { dry_run: true })
-        }
-        "auth" if hlp(&words) => Ok(Action::Help),
+        "--update" | "self-update" if words.len() == 1 => Ok(Action::SelfUpdate { dry_run: false }),
+        "--update" | "self-update" if words.len() == 2 && words[1] == "--dry-run" => Ok(Action::SelfUpdate { dry_run: true }),
+        "auth" | "config" | "cache" | "security" | "gate" | "experimental" | "outdated" | "history" | "self-update" | "completions" | "templates" | "db" if hlp(&words) => Ok(Action::Help),
         "auth" => Ok(Action::Auth(words[1..].to_vec())),
-        "config" if hlp(&words) => Ok(Action::Help),
         "config" => Ok(Action::Config(words[1..].to_vec())),
-        "cache" if hlp(&words) => Ok(Action::Help),
         "cache" => Ok(Action::Cache(words[1..].to_vec())),
-        "security" if hlp(&words) => Ok(Action::Help),
         "security" => Ok(Action::Security(words[1..].to_vec())),
-        "gate" if hlp(&words) => Ok(Action::Help),
         "gate" => Ok(Action::Gate(words[1..].to_vec())),
-        "experimental" if hlp(&words) => Ok(Action::Help),
         "experimental" => Ok(Action::Experimental(words[1..].to_vec())),
-        "templates" | "db" if hlp(&words) => Ok(Action::Help),
+        "self-update" => Err("usage: terminal-jarvis self-update [--dry-run]".to_string()),
+        "history" => Ok(Action::History(words[1..].to_vec())),
+        "outdated" => Ok(Action::Outdated(words[1..].to_vec())),
+        "completions" => Ok(Action::Completions(words[1..].to_vec())),
+        "--install-completions" => Ok(Action::Completions([vec!["--install".into()], words[1..].to_vec()].concat())),
         "templates" | "db" => Ok(Action::Legacy(words[0].clone())),
         other if other.starts_with('-') => Err(format!("unknown flag '{other}'; use --help, --version, -v, or --info")),
         other => Ok(Action::Direct { harness: other.to_string(), extra: words[1..].to_vec() }),
@@ -70,6 +74,12 @@ fn version(words: &[String]) -> Result<Action, String> {
 #[rustfmt::skip]
 fn one(w: &[String], c: &str) -> Result<String, String> { match w { [_, v] => Ok(v.clone()), _ => Err(format!("usage: terminal-jarvis {c} <harness>")) } }
 #[rustfmt::skip]
+fn show(w: &[String]) -> Result<Action, String> {
+    let usage = || format!("usage: terminal-jarvis {} <harness> [--format text|json|markdown]", w[0]);
+    let (name, format) = match &w[1..] { [h] => (h, "text"), [h, f, v] if f == "--format" => (h, v.as_str()), [h, f] => (h, f.strip_prefix("--format=").ok_or_else(usage)?), _ => return Err(usage()) };
+    Ok(Action::Show { name: name.clone(), format: ShowFormat::parse(format)? })
+}
+#[rustfmt::skip]
 fn optional_one(w: &[String], c: &str) -> Result<Option<String>, String> { match w { [_] => Ok(None), [_, v] => Ok(Some(v.clone())), _ => Err(format!("usage: terminal-jarvis {c} [harness]")) } }
 #[rustfmt::skip]
 fn plan(words: &[String]) -> Result<Action, String> { match words { [c] => Ok(Action::Plan { harness: None, capability: cap(c)? }), [h, c] => Ok(Action::Plan { harness: Some(h.clone()), capability: cap(c)? }), _ => Err("usage: terminal-jarvis plan [harness] <capability>".to_string()) } }
@@ -42,8 +42,12 @@ fn version_rejects_extra() {
 }
 #[test]
 fn list_status_check_current_use_show() {
-    assert_eq!(a(&["tj", "list"]), Action::List);
-    assert_eq!(a(&["tj", "tools"]), Action::List);
+    assert_eq!(a(&["tj", "list"]), Action::List(vec![]));
+    assert_eq!(a(&["tj", "tools"]), Action::List(vec![]));
+    assert_eq!(
+        a(&["tj", "list", "--sort", "name"]),
+        Action::List(vec!["--sort".to_string(), "name".to_string()])
+    );
     assert_eq!(a(&["tj", "check"]), Action::Check);
     assert_eq!(a(&["tj", "status"]), Action::Check);
     assert_eq!(a(&["tj", "current"]), Action::Current);
@@ -53,8 +57,25 @@ fn list_status_check_current_use_show() {
     );
     assert_eq!(
         a(&["tj", "show", "opencode"]),
-        Action::Show("opencode".to_string())
+        Action::Show {
+            name: "opencode".to_string(),
+            format: ShowFormat::Text
+        }
     );
+    for args in [
+        &["tj", "info", "opencode", "--format", "json"][..],
+        &["tj", "show", "opencode", "--format=json"][..],
+    ] {
+        assert_eq!(
+            a(args),
+            Action::Show {
+                name: "opencode".to_string(),
+                format: ShowFormat::Json
+            }
+        );
+    }
+    assert!(e(&["tj", "show", "opencode", "--format", "yaml"]).is_err());
+    assert!(e(&["tj", "show", "opencode", "--format"]).is_err());
     assert!(e(&["tj", "use"]).is_err());
     assert!(e(&["tj", "show"]).is_err());
 }
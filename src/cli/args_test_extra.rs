@@ -75,6 +75,7 @@ fn update_auth_config_cache_security_legacy() {
 fn version_and_update_reject_unexpected_trailing_args() {
     assert!(e(&["tj", "--version", "version"]).is_err());
     assert!(e(&["tj", "--update", "foo"]).is_err());
+    assert!(e(&["tj", "self-update", "foo"]).is_err());
 }
 
 #[test]
@@ -22,3 +22,59 @@ fn gate_and_experimental_help_route_to_top_level_help() {
     assert_eq!(action(&["tj", "gate", "--help"]), Action::Help);
     assert_eq!(action(&["tj", "experimental", "-h"]), Action::Help);
 }
+
+#[test]
+fn completions_and_install_flag_share_one_action() {
+    let words = |words: &[&str]| words.iter().map(|word| word.to_string()).collect();
+    assert_eq!(
+        action(&["tj", "completions", "zsh"]),
+        Action::Completions(words(&["zsh"]))
+    );
+    assert_eq!(
+        action(&["tj", "--install-completions", "fish"]),
+        Action::Completions(words(&["--install", "fish"]))
+    );
+    assert_eq!(action(&["tj", "completions", "--help"]), Action::Help);
+}
+
+#[test]
+fn search_and_tools_search_route_to_search() {
+    let query = vec!["pair".to_string(), "--installed-only".to_string()];
+    assert_eq!(
+        action(&["tj", "search", "pair", "--installed-only"]),
+        Action::Search(query.clone())
+    );
+    assert_eq!(
+        action(&["tj", "tools", "search", "pair", "--installed-only"]),
+        Action::Search(query)
+    );
+    assert_eq!(action(&["tj", "tools"]), Action::List(vec![]));
+}
+
+#[test]
+fn self_update_dry_run_matches_the_update_flag() {
+    assert_eq!(
+        action(&["tj", "self-update", "--dry-run"]),
+        action(&["tj", "--update", "--dry-run"])
+    );
+}
+
+#[test]
+fn update_only_outdated_is_its_own_action() {
+    assert_eq!(
+        action(&["tj", "update", "--only-outdated"]),
+        Action::UpdateOutdated
+    );
+    assert_eq!(
+        action(&["tj", "update", "codex"]),
+        Action::Update(Some("codex".into()))
+    );
+}
+
+#[test]
+fn update_check_is_outdated() {
+    assert_eq!(
+        action(&["tj", "update", "check", "--json"]),
+        Action::Outdated(vec!["--json".into()])
+    );
+}
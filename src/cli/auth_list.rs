@@ -0,0 +1,82 @@
+use super::{style, table};
+use crate::contracts::Harness;
+use crate::security::{self, Provider};
+
+pub fn render(harnesses: &[Harness]) -> String {
+    let rows = harnesses
+        .iter()
+        .flat_map(|harness| {
+            harness.env.iter().filter_map(|name| {
+                let value = std::env::var(name).ok()?;
+                Some(vec![
+                    harness.name.clone(),
+                    name.clone(),
+                    mask(&value),
+                    check(name, &value),
+                ])
+            })
+        })
+        .collect::<Vec<_>>();
+    if style::plain() {
+        if rows.is_empty() {
+            return "no harness credentials found in the environment\n".to_string();
+        }
+        return rows
+            .iter()
+            .map(|row| format!("{} {}={} {}\n", row[0], row[1], row[2], row[3]))
+            .collect();
+    }
+    if rows.is_empty() {
+        return style::warning("No harness credentials found in the environment.\n");
+    }
+    table::render(
+        "Harness Credentials",
+        &["HARNESS", "VARIABLE", "VALUE", "CHECK"],
+        &rows,
+    )
+}
+
+pub fn mask(value: &str) -> String {
+    let chars = value.chars().collect::<Vec<_>>();
+    if chars.len() < 10 {
+        return "********".to_string();
+    }
+    let head = chars[..4].iter().collect::<String>();
+    let tail = chars[chars.len() - 2..].iter().collect::<String>();
+    format!("{head}...{tail}")
+}
+
+fn check(name: &str, value: &str) -> String {
+    match Provider::for_env(name) {
+        Some(provider) => {
+            let check = security::validate_api_key_format(value, provider);
+            if check.confidence < 1.0 {
+                format!("warn: {}", check.reason)
+            } else {
+                "ok".to_string()
+            }
+        }
+        None if value.trim().is_empty() => "warn: value is empty".to_string(),
+        None => "ok".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, mask};
+
+    #[test]
+    fn mask_keeps_first_four_and_last_two_characters() {
+        assert_eq!(mask("sk-ant-api03-abcdef"), "sk-a...ef");
+        for short in ["", "a", "short", "123456789"] {
+            assert_eq!(mask(short), "********", "{short}");
+        }
+    }
+
+    #[test]
+    fn check_flags_provider_mismatches_and_empty_values() {
+        assert!(check("ANTHROPIC_API_KEY", "sk-proj-123").starts_with("warn:"));
+        assert_eq!(check("KILO_API_KEY", "anything"), "ok");
+        assert_eq!(check("KILO_API_KEY", " "), "warn: value is empty");
+    }
+}
@@ -20,9 +20,11 @@ pub fn auth(words: &[String], harnesses: &[Harness]) -> Result<String, String> {
         [] => Ok(output::auth_notice(VERSION)),
         [action] if action == "manage" => Ok(output::auth_notice(VERSION)),
         [action, name] if action == "help" => auth_for(name, harnesses),
+        [action] if action == "list" => Ok(super::auth_list::render(harnesses)),
         [action, name] if action == "set" => auth_set_for(name, harnesses),
+        [action, name] if action == "delete" => auth_delete_for(name, harnesses),
         [name] => auth_for(name, harnesses),
-        _ => Err("usage: terminal-jarvis auth [help|set] <harness>".to_string()),
+        _ => Err("usage: terminal-jarvis auth [list|help|set|delete] <harness>".to_string()),
     }
 }
 
@@ -37,7 +39,10 @@ pub fn config(
         [action] if action == "show" => Ok(config_output::show(catalog_root, home, session)),
         [action] if action == "path" => Ok(config_output::paths(catalog_root, home)),
         [action] if action == "reset" => Ok(config_output::reset(VERSION)),
-        _ => Err("usage: terminal-jarvis config [show|path|reset]".to_string()),
+        _ => Err(
+            "usage: terminal-jarvis config [show [--effective]|path|reset|validate [catalog]]"
+                .to_string(),
+        ),
     }
 }
 
@@ -46,17 +51,27 @@ pub fn legacy(command: &str) -> String {
 }
 
 fn auth_for(name: &str, harnesses: &[Harness]) -> Result<String, String> {
-    auth_detail(
-        name,
-        harnesses,
-        &format!("credential storage is not active in v{VERSION}; export env vars in your shell"),
-    )
+    let note = auth_note(crate::context::environment().is_headless);
+    auth_detail(name, harnesses, &note)
+}
+
+fn auth_note(headless: bool) -> String {
+    let mut note =
+        format!("credential storage is not active in v{VERSION}; export env vars in your shell");
+    if headless {
+        note.push_str("; no display was detected, so browser sign-in may not open here");
+    }
+    note
 }
 
 fn auth_set_for(name: &str, harnesses: &[Harness]) -> Result<String, String> {
     auth_detail(name, harnesses, "terminal-jarvis does not persist credentials; nothing was stored. Export the env vars in your shell")
 }
 
+fn auth_delete_for(name: &str, harnesses: &[Harness]) -> Result<String, String> {
+    auth_detail(name, harnesses, "terminal-jarvis does not persist credentials; nothing was removed. Unset the env vars in your shell")
+}
+
 fn auth_detail(name: &str, harnesses: &[Harness], note: &str) -> Result<String, String> {
     let harness = harnesses
         .iter()
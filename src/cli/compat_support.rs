@@ -1,14 +1,33 @@
 use crate::contracts::{EnvMode, Harness};
-use crate::security;
+use crate::security::{self, Provider};
 
 pub fn auth_status(harness: &Harness) -> String {
     let missing = security::missing_env(harness);
-    if missing.is_empty() {
-        return "ready".to_string();
-    }
-    match harness.env_mode {
-        EnvMode::Any => format!("missing one of: {}", missing.join(", ")),
-        EnvMode::All => format!("missing: {}", missing.join(", ")),
-        EnvMode::None => "ready".to_string(),
+    let status = if missing.is_empty() {
+        "ready".to_string()
+    } else {
+        match harness.env_mode {
+            EnvMode::Any => format!("missing one of: {}", missing.join(", ")),
+            EnvMode::All => format!("missing: {}", missing.join(", ")),
+            EnvMode::None => "ready".to_string(),
+        }
+    };
+    let warnings = key_format_warnings(harness);
+    if warnings.is_empty() {
+        return status;
     }
+    format!("{status}; check {}", warnings.join("; "))
+}
+
+pub fn key_format_warnings(harness: &Harness) -> Vec<String> {
+    harness
+        .env
+        .iter()
+        .filter_map(|name| {
+            let provider = Provider::for_env(name)?;
+            let value = std::env::var(name).ok()?;
+            let check = security::validate_api_key_format(&value, provider);
+            (check.confidence < 1.0).then(|| format!("{name}: {}", check.reason))
+        })
+        .collect()
 }
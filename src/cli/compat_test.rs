@@ -16,6 +16,8 @@ fn harness(name: &str) -> Harness {
         binary: name.to_string(),
         env_mode: EnvMode::None,
         env: vec![],
+        full_screen: false,
+        project_args: vec![],
         capabilities: Capability::ALL.iter().map(|c| cap(*c)).collect(),
     }
 }
@@ -34,9 +36,14 @@ fn auth_routes() {
     assert!(auth(&["help".to_string(), "opencode".to_string()], &hs).is_ok());
     assert!(auth(&["set".to_string(), "opencode".to_string()], &hs).is_ok());
     assert!(auth(&["opencode".to_string()], &hs).is_ok());
+    assert!(auth(&["list".to_string()], &hs).is_ok());
+    let delete = auth(&["delete".to_string(), "opencode".to_string()], &hs).unwrap();
+    assert!(delete.contains("nothing was removed"));
     assert!(auth(&["unknown".to_string()], &hs).is_err());
     assert!(auth(&["help".to_string(), "unknown".to_string()], &hs).is_err());
     assert!(auth(&["a".to_string(), "b".to_string(), "c".to_string()], &hs).is_err());
+    assert!(auth_note(true).ends_with("browser sign-in may not open here"));
+    assert!(!auth_note(false).contains("display"));
 }
 #[test]
 fn config_routes() {
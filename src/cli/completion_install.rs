@@ -0,0 +1,56 @@
+use super::completion_scripts::script;
+use crate::contracts::Harness;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+pub fn install(
+    shell: &str,
+    harnesses: &[Harness],
+    home: &Path,
+    user_home: &Path,
+) -> Result<(PathBuf, Option<PathBuf>), String> {
+    let body = script(shell, harnesses)?;
+    let (path, rc) = match shell {
+        "fish" => (
+            user_home.join(".config/fish/completions/terminal-jarvis.fish"),
+            None,
+        ),
+        _ => {
+            let rc = user_home.join(format!(".{shell}rc"));
+            (
+                home.join(format!("completions/terminal-jarvis.{shell}")),
+                Some(rc),
+            )
+        }
+    };
+    let write = |path: &Path, text: &str| {
+        let parent = path.parent().unwrap_or(Path::new("."));
+        fs::create_dir_all(parent)
+            .and_then(|_| fs::write(path, text))
+            .map_err(|error| format!("failed to write {}: {error}", path.display()))
+    };
+    write(&path, &body)?;
+    if let Some(rc) = &rc {
+        append_once(rc, &format!("source \"{}\"", path.display()))
+            .map_err(|error| format!("failed to update {}: {error}", rc.display()))?;
+    }
+    Ok((path, rc))
+}
+
+fn append_once(rc: &Path, line: &str) -> std::io::Result<()> {
+    let existing = match fs::read_to_string(rc) {
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+        result => result?,
+    };
+    if existing.lines().any(|current| current.trim() == line) {
+        return Ok(());
+    }
+    let separator = if existing.is_empty() || existing.ends_with('\n') {
+        ""
+    } else {
+        "\n"
+    };
+    let mut file = fs::OpenOptions::new().create(true).append(true).open(rc)?;
+    file.write_all(format!("{separator}{line}\n").as_bytes())
+}
@@ -0,0 +1,97 @@
+use crate::contracts::{Capability, Harness};
+
+const COMMANDS: [&str; 23] = [
+    "list",
+    "search",
+    "show",
+    "use",
+    "current",
+    "plan",
+    "run",
+    "check",
+    "install",
+    "update",
+    "outdated",
+    "history",
+    "auth",
+    "config",
+    "cache",
+    "security",
+    "gate",
+    "version",
+    "self-update",
+    "completions",
+    "help",
+    "--plain",
+    "--info",
+];
+const HARNESS_COMMANDS: &str = "show info use install update";
+
+pub fn script(shell: &str, harnesses: &[Harness]) -> Result<String, String> {
+    let names = harnesses
+        .iter()
+        .map(|harness| harness.name.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let capabilities = Capability::ALL.map(Capability::as_str).join(" ");
+    let commands = COMMANDS.join(" ");
+    match shell {
+        "bash" => Ok(format!(
+            r#"# terminal-jarvis bash completion
+_terminal_jarvis() {{
+    local cur=${{COMP_WORDS[COMP_CWORD]}}
+    local harnesses="{names}"
+    local words
+    if [ "$COMP_CWORD" -eq 1 ]; then
+        words="{commands} $harnesses"
+    else
+        case ${{COMP_WORDS[1]}} in
+            {cases}) words=$harnesses ;;
+            run|plan) words="$harnesses {capabilities}" ;;
+            completions) words="bash zsh fish --install" ;;
+            tools) words=search ;;
+            *) return ;;
+        esac
+    fi
+    COMPREPLY=($(compgen -W "$words" -- "$cur"))
+}}
+complete -F _terminal_jarvis terminal-jarvis
+"#,
+            cases = HARNESS_COMMANDS.replace(' ', "|"),
+        )),
+        "zsh" => Ok(format!(
+            r#"#compdef terminal-jarvis
+(( $+functions[compdef] )) || {{ autoload -Uz compinit && compinit }}
+_terminal_jarvis() {{
+    local -a harnesses
+    harnesses=({names})
+    if (( CURRENT == 2 )); then
+        compadd -- {commands} $harnesses
+        return
+    fi
+    case $words[2] in
+        {cases}) compadd -- $harnesses ;;
+        run|plan) compadd -- $harnesses {capabilities} ;;
+        completions) compadd -- bash zsh fish --install ;;
+        tools) compadd -- search ;;
+    esac
+}}
+compdef _terminal_jarvis terminal-jarvis
+"#,
+            cases = HARNESS_COMMANDS.replace(' ', "|"),
+        )),
+        "fish" => Ok(format!(
+            r#"# terminal-jarvis fish completion
+complete -c terminal-jarvis -f
+complete -c terminal-jarvis -n __fish_use_subcommand -a "{commands} {names}"
+complete -c terminal-jarvis -n "__fish_seen_subcommand_from {HARNESS_COMMANDS}" -a "{names}"
+complete -c terminal-jarvis -n "__fish_seen_subcommand_from run plan" -a "{names} {capabilities}"
+complete -c terminal-jarvis -n "__fish_seen_subcommand_from completions" -a "bash zsh fish --install"
+complete -c terminal-jarvis -n "__fish_seen_subcommand_from tools" -a search
+"#
+        )),
+        other => Err(format!(
+            "unsupported shell '{other}'; expected bash, zsh, or fish"
+        )),
+    }
+}
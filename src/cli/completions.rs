@@ -0,0 +1,62 @@
+use super::completion_install::install;
+use super::completion_scripts::script;
+use super::{style, table};
+use crate::contracts::Harness;
+use std::path::Path;
+
+const USAGE: &str =
+    "usage: terminal-jarvis completions <bash|zsh|fish> | --install [bash|zsh|fish]";
+
+pub fn handle(words: &[String], harnesses: &[Harness], home: &Path) -> Result<String, String> {
+    match words {
+        [shell] if shell != "--install" => script(shell, harnesses),
+        [flag, rest @ ..] if flag == "--install" && rest.len() <= 1 => {
+            let shell = match rest.first() {
+                Some(shell) => shell.clone(),
+                None => detect_shell()?,
+            };
+            let user_home = std::env::var_os("HOME")
+                .filter(|value| !value.is_empty())
+                .ok_or("HOME is not set; cannot find shell config files")?;
+            let (script_path, rc) = install(&shell, harnesses, home, Path::new(&user_home))?;
+            Ok(installed(&shell, &script_path, rc.as_deref()))
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn detect_shell() -> Result<String, String> {
+    let shell = std::env::var("SHELL").unwrap_or_default();
+    match shell.rsplit('/').next().unwrap_or_default() {
+        name @ ("bash" | "zsh" | "fish") => Ok(name.to_string()),
+        _ => Err(format!(
+            "cannot detect a supported shell from SHELL='{shell}'; pass bash, zsh, or fish"
+        )),
+    }
+}
+
+fn installed(shell: &str, script_path: &Path, rc: Option<&Path>) -> String {
+    let sourced = rc.map_or_else(
+        || "loaded automatically by fish".to_string(),
+        |rc| format!("sourced from {}", rc.display()),
+    );
+    if style::plain() {
+        return format!(
+            "{shell} completions written to {} ({sourced})\n",
+            script_path.display()
+        );
+    }
+    table::fields(
+        "Shell Completions",
+        &[
+            ("SHELL", shell.to_string()),
+            ("SCRIPT", script_path.display().to_string()),
+            ("LOADED", sourced),
+            ("NEXT STEP", "open a new shell".to_string()),
+        ],
+    )
+}
+
+#[cfg(test)]
+#[path = "completions_test.rs"]
+mod tests;
@@ -0,0 +1,91 @@
+use super::*;
+use crate::contracts::EnvMode;
+use std::fs;
+use std::path::PathBuf;
+
+fn harness(name: &str) -> Harness {
+    Harness {
+        name: name.into(),
+        display: name.into(),
+        description: String::new(),
+        binary: name.into(),
+        env_mode: EnvMode::None,
+        env: vec![],
+        full_screen: false,
+        project_args: vec![],
+        capabilities: vec![],
+    }
+}
+
+fn temp(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("tj-completions-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn scripts_list_subcommands_harnesses_and_capabilities() {
+    let harnesses = [harness("codex"), harness("aider")];
+    let bash = script("bash", &harnesses).unwrap();
+    for word in [
+        "list",
+        "install",
+        "history",
+        "codex aider",
+        "headless",
+        "yolo",
+    ] {
+        assert!(bash.contains(word), "{word}");
+    }
+    assert!(bash.contains("complete -F _terminal_jarvis terminal-jarvis"));
+    assert!(script("zsh", &harnesses)
+        .unwrap()
+        .contains("compdef _terminal_jarvis"));
+    assert!(script("fish", &harnesses)
+        .unwrap()
+        .contains("-a \"codex aider\""));
+    assert!(script("tcsh", &harnesses)
+        .unwrap_err()
+        .contains("unsupported shell"));
+}
+
+#[test]
+fn install_writes_the_script_and_sources_it_once() {
+    let root = temp("bash");
+    let (home, user) = (root.join("tj"), root.join("user"));
+    fs::create_dir_all(&user).unwrap();
+    fs::write(user.join(".bashrc"), "alias ll='ls -l'").unwrap();
+    for _ in 0..2 {
+        let (script, rc) = install("bash", &[harness("codex")], &home, &user).unwrap();
+        assert!(!fs::read_to_string(script).unwrap().is_empty());
+        assert_eq!(rc, Some(user.join(".bashrc")));
+    }
+    let rc = fs::read_to_string(user.join(".bashrc")).unwrap();
+    assert!(rc.starts_with("alias ll='ls -l'\nsource \""));
+    assert_eq!(rc.matches("source ").count(), 1);
+}
+
+#[test]
+fn fish_completions_go_to_the_autoload_directory() {
+    let root = temp("fish");
+    let (script, rc) = install("fish", &[], &root.join("tj"), &root.join("user")).unwrap();
+    assert!(script.ends_with(".config/fish/completions/terminal-jarvis.fish"));
+    assert!(fs::metadata(script).unwrap().len() > 0);
+    assert_eq!(rc, None);
+}
+
+#[test]
+fn every_command_in_help_is_completed() {
+    let scripts = ["bash", "zsh", "fish"].map(|shell| script(shell, &[]).unwrap());
+    let commands = include_str!("help.txt")
+        .lines()
+        .filter_map(|line| line.strip_prefix("terminal-jarvis "))
+        .filter_map(|rest| rest.split_whitespace().next())
+        .filter(|command| !command.starts_with('['));
+    for command in commands {
+        for script in &scripts {
+            let words = script.split(|char: char| char.is_whitespace() || char == '"');
+            assert!(words.clone().any(|word| word == command), "{command}");
+        }
+    }
+}
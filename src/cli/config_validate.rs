@@ -0,0 +1,40 @@
+use super::{style, table};
+use std::path::Path;
+
+pub fn run(words: &[String], catalog_root: &Path) -> Result<(i32, String), String> {
+    let root = match words {
+        [] => catalog_root,
+        [path] => Path::new(path),
+        _ => return Err("usage: terminal-jarvis config validate [catalog]".to_string()),
+    };
+    let result = crate::catalog::check(root);
+    Ok((i32::from(result.is_err()), validation(root, result)))
+}
+
+fn validation(root: &Path, result: Result<usize, Vec<String>>) -> String {
+    let problems = match result {
+        Ok(count) if style::plain() => {
+            return format!("catalog ok: {count} harnesses at {}\n", root.display())
+        }
+        Ok(count) => {
+            let summary = format!("{count} harnesses at {}", root.display());
+            return table::fields("Catalog Validation", &[("OK", summary)]);
+        }
+        Err(problems) => problems,
+    };
+    if style::plain() {
+        let lines = problems.iter().map(|problem| format!("error: {problem}\n"));
+        let plural = if problems.len() == 1 { "" } else { "s" };
+        let total = format!("catalog invalid: {} problem{plural}\n", problems.len());
+        return lines.collect::<String>() + &total;
+    }
+    let rows = problems
+        .into_iter()
+        .map(|problem| vec![problem])
+        .collect::<Vec<_>>();
+    table::render(
+        &format!("Catalog Validation: {}", root.display()),
+        &["PROBLEM"],
+        &rows,
+    )
+}
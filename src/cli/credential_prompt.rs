@@ -0,0 +1,97 @@
+use super::style;
+use crate::contracts::LaunchOptions;
+use std::io::{self, BufRead, Write};
+use std::process::{Command, Stdio};
+
+pub fn ask(
+    notice: &str,
+    missing: &[String],
+    all: bool,
+    options: &mut LaunchOptions,
+) -> Result<(), String> {
+    eprintln!("{}", style::warning(notice));
+    eprint!("[k] enter a key for this launch  [c] continue anyway  [a] abort (default c): ");
+    let _ = io::stderr().flush();
+    let asked = match read_line()?.trim() {
+        "a" | "A" | "abort" => return Err("launch aborted: missing credentials".to_string()),
+        "k" | "K" | "key" if all || missing.len() < 2 => missing.to_vec(),
+        "k" | "K" | "key" => vec![choose(missing)?],
+        _ => return Ok(()),
+    };
+    asked.into_iter().try_for_each(|name| {
+        let value = read_hidden(&name)?;
+        options.env.push((name, value));
+        Ok(())
+    })
+}
+
+fn choose(missing: &[String]) -> Result<String, String> {
+    for (index, name) in missing.iter().enumerate() {
+        eprintln!("  {}) {name}", index + 1);
+    }
+    eprint!("which variable? (default 1): ");
+    let _ = io::stderr().flush();
+    let answer = read_line()?;
+    pick(answer.trim(), missing).cloned().ok_or_else(|| {
+        format!(
+            "launch aborted: '{}' is not one of the listed variables",
+            answer.trim()
+        )
+    })
+}
+
+fn pick<'a>(answer: &str, missing: &'a [String]) -> Option<&'a String> {
+    if answer.is_empty() {
+        return missing.first();
+    }
+    match answer.parse::<usize>() {
+        Ok(number) => missing.get(number.checked_sub(1)?),
+        Err(_) => missing
+            .iter()
+            .find(|name| name.eq_ignore_ascii_case(answer)),
+    }
+}
+
+const HIDDEN_READ: &str = "if saved=$(stty -g 2>/dev/null); then \
+    trap 'stty \"$saved\"; exit 130' HUP INT TERM; stty -echo; fi; \
+    IFS= read -r value; code=$?; [ -n \"$saved\" ] && stty \"$saved\"; \
+    printf '%s' \"$value\"; exit $code";
+
+fn read_hidden(name: &str) -> Result<String, String> {
+    eprint!("{name} (input hidden, not saved): ");
+    let _ = io::stderr().flush();
+    let value = match cfg!(unix) {
+        true => hidden(Command::new("sh").args(["-c", HIDDEN_READ]))?,
+        false => read_line()?,
+    };
+    match value.trim() {
+        "" => Err(format!("launch aborted: no value entered for {name}")),
+        value => Ok(value.to_string()),
+    }
+}
+
+fn hidden(command: &mut Command) -> Result<String, String> {
+    let output = command
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|error| format!("failed to read answer: {error}"))?;
+    eprintln!();
+    match output.status.success() {
+        true => Ok(String::from_utf8_lossy(&output.stdout).to_string()),
+        false => Err("launch aborted: key prompt interrupted".to_string()),
+    }
+}
+
+pub fn read_line() -> Result<String, String> {
+    let mut line = String::new();
+    io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .map_err(|error| format!("failed to read answer: {error}"))?;
+    Ok(line)
+}
+
+#[cfg(test)]
+#[path = "credential_prompt_test.rs"]
+mod tests;
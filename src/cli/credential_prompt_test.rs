@@ -0,0 +1,66 @@
+use super::{pick, HIDDEN_READ};
+
+fn names() -> Vec<String> {
+    ["OPENAI_API_KEY", "ANTHROPIC_API_KEY", "GOOGLE_API_KEY"]
+        .map(String::from)
+        .to_vec()
+}
+
+#[test]
+fn any_mode_picks_by_number_name_or_default() {
+    let missing = names();
+    assert_eq!(pick("", &missing), Some(&missing[0]));
+    assert_eq!(pick("2", &missing), Some(&missing[1]));
+    assert_eq!(pick("google_api_key", &missing), Some(&missing[2]));
+}
+
+#[test]
+fn unknown_choices_are_rejected() {
+    let missing = names();
+    for answer in ["0", "4", "GEMINI_API_KEY", "-1"] {
+        assert_eq!(pick(answer, &missing), None, "{answer}");
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn interrupted_hidden_read_restores_the_saved_terminal_settings() {
+    use std::os::unix::fs::PermissionsExt;
+    use std::process::{Command, Stdio};
+
+    let dir = std::env::temp_dir().join(format!("tj-hidden-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    let (stty, log) = (dir.join("stty"), dir.join("stty.log"));
+    let script = format!(
+        "#!/bin/sh\n[ \"$1\" = -g ] && {{ echo saved-settings; exit 0; }}\necho \"$*\" >> {}\n",
+        log.display()
+    );
+    std::fs::write(&stty, script).unwrap();
+    std::fs::set_permissions(&stty, std::fs::Permissions::from_mode(0o755)).unwrap();
+    let path = format!(
+        "{}:{}",
+        dir.display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+    let mut child = Command::new("sh")
+        .args(["-c", HIDDEN_READ])
+        .env("PATH", path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let logged = || std::fs::read_to_string(&log).unwrap_or_default();
+    while logged().is_empty() {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    let pid = child.id().to_string();
+    assert!(Command::new("kill")
+        .args(["-INT", &pid])
+        .status()
+        .unwrap()
+        .success());
+    let status = child.wait().unwrap();
+    assert_eq!(status.code(), Some(130));
+    assert_eq!(logged(), "-echo\nsaved-settings\n");
+}
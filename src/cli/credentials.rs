@@ -0,0 +1,95 @@
+use super::{credential_prompt, resolve, style, verbosity};
+use crate::contracts::{Capability, EnvMode, Harness, LaunchOptions};
+use crate::security;
+use std::io::IsTerminal;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Decision {
+    Proceed,
+    Warn(String),
+    Prompt(String),
+    Refuse(String),
+}
+
+pub fn check(
+    harnesses: &[Harness],
+    invocation: &resolve::Invocation,
+    options: &mut LaunchOptions,
+) -> Result<(), String> {
+    let Some(harness) = harnesses
+        .iter()
+        .find(|harness| harness.name == invocation.harness)
+    else {
+        return Ok(());
+    };
+    let missing = missing_credentials(harness, invocation.capability, options);
+    let interactive =
+        !options.stdin && std::io::stdin().is_terminal() && std::io::stderr().is_terminal();
+    match decide(
+        credential_notice(harness, &missing),
+        options.require_auth,
+        interactive,
+    ) {
+        Decision::Proceed => Ok(()),
+        Decision::Warn(notice) => {
+            verbosity::notice(&style::warning(&format!("warning: {notice}")));
+            Ok(())
+        }
+        Decision::Refuse(notice) => Err(format!("{notice} (--require-auth)")),
+        Decision::Prompt(notice) => {
+            let all = harness.env_mode == EnvMode::All;
+            credential_prompt::ask(&notice, &missing, all, options)
+        }
+    }
+}
+
+pub fn decide(notice: Option<String>, require_auth: bool, interactive: bool) -> Decision {
+    match (notice, require_auth, interactive) {
+        (None, _, _) => Decision::Proceed,
+        (Some(notice), _, true) => Decision::Prompt(notice),
+        (Some(notice), true, false) => Decision::Refuse(notice),
+        (Some(notice), false, false) => Decision::Warn(notice),
+    }
+}
+
+fn missing_credentials(
+    harness: &Harness,
+    capability: Capability,
+    options: &LaunchOptions,
+) -> Vec<String> {
+    let launches = matches!(
+        capability,
+        Capability::Ui | Capability::Headless | Capability::Yolo
+    );
+    let mut missing = security::missing_env(harness);
+    if !launches
+        || harness.env_mode == EnvMode::Any && harness.env.iter().any(|name| options.provides(name))
+    {
+        missing.clear();
+    }
+    missing.retain(|name| !options.provides(name));
+    missing
+}
+
+fn credential_notice(harness: &Harness, missing: &[String]) -> Option<String> {
+    if missing.is_empty() {
+        return None;
+    }
+    let needed = match harness.env_mode {
+        EnvMode::All => "missing",
+        _ => "set one of",
+    };
+    Some(format!(
+        "{} may stop for interactive sign-in; {needed}: {}. See `terminal-jarvis auth help {}`",
+        harness.display,
+        missing.join(", "),
+        harness.name
+    ))
+}
+
+#[cfg(test)]
+#[path = "credentials_test.rs"]
+mod tests;
+#[cfg(test)]
+#[path = "credentials_test_matrix.rs"]
+mod tests_matrix;
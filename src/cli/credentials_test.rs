@@ -0,0 +1,67 @@
+use super::*;
+use crate::contracts::EnvMode;
+
+pub fn none() -> LaunchOptions {
+    LaunchOptions::default()
+}
+
+pub fn harness(env_mode: EnvMode, env: &[&str]) -> Harness {
+    Harness {
+        name: "aider".into(),
+        display: "Aider".into(),
+        description: String::new(),
+        binary: "aider".into(),
+        env_mode,
+        env: env.iter().map(|name| name.to_string()).collect(),
+        full_screen: false,
+        project_args: vec![],
+        capabilities: vec![],
+    }
+}
+
+pub fn notice_for(
+    harness: &Harness,
+    capability: Capability,
+    options: &LaunchOptions,
+) -> Option<String> {
+    credential_notice(harness, &missing_credentials(harness, capability, options))
+}
+
+#[test]
+fn launch_without_any_provider_key_names_the_env_vars() {
+    let aider = harness(EnvMode::Any, &["TJ_GUARD_UNSET_A", "TJ_GUARD_UNSET_B"]);
+    let notice = notice_for(&aider, Capability::Ui, &none()).unwrap();
+    assert!(
+        notice.contains("TJ_GUARD_UNSET_A, TJ_GUARD_UNSET_B"),
+        "{notice}"
+    );
+    assert!(
+        notice.contains("terminal-jarvis auth help aider"),
+        "{notice}"
+    );
+    assert!(notice_for(&aider, Capability::Headless, &none()).is_some());
+}
+
+#[test]
+fn keyless_and_non_launch_capabilities_stay_quiet() {
+    assert!(notice_for(&harness(EnvMode::None, &[]), Capability::Ui, &none()).is_none());
+    let aider = harness(EnvMode::Any, &["TJ_GUARD_UNSET_A"]);
+    assert!(notice_for(&aider, Capability::Download, &none()).is_none());
+    assert!(notice_for(&aider, Capability::Version, &none()).is_none());
+}
+
+#[test]
+fn any_present_key_satisfies_the_check() {
+    let aider = harness(EnvMode::Any, &["TJ_GUARD_UNSET_A", "PATH"]);
+    assert!(notice_for(&aider, Capability::Ui, &none()).is_none());
+}
+
+#[test]
+fn one_off_tool_env_counts_as_a_provided_key() {
+    let aider = harness(EnvMode::Any, &["TJ_GUARD_UNSET_A", "TJ_GUARD_UNSET_B"]);
+    let options = LaunchOptions {
+        env: vec![("TJ_GUARD_UNSET_B".into(), "key".into())],
+        ..LaunchOptions::default()
+    };
+    assert!(notice_for(&aider, Capability::Ui, &options).is_none());
+}
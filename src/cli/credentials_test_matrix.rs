@@ -0,0 +1,54 @@
+use super::tests::{harness, none, notice_for};
+use super::*;
+
+#[test]
+fn decision_matrix_covers_env_tool_env_and_neither() {
+    let provided = LaunchOptions {
+        env: vec![("TJ_GUARD_UNSET_A".into(), "key".into())],
+        ..LaunchOptions::default()
+    };
+    let cases = [
+        (
+            harness(EnvMode::Any, &["TJ_GUARD_UNSET_A", "PATH"]),
+            none(),
+            false,
+        ),
+        (
+            harness(EnvMode::All, &["TJ_GUARD_UNSET_A", "PATH"]),
+            none(),
+            true,
+        ),
+        (
+            harness(EnvMode::All, &["TJ_GUARD_UNSET_A", "PATH"]),
+            provided.clone(),
+            false,
+        ),
+        (
+            harness(EnvMode::Any, &["TJ_GUARD_UNSET_A"]),
+            provided,
+            false,
+        ),
+        (harness(EnvMode::Any, &["TJ_GUARD_UNSET_A"]), none(), true),
+        (harness(EnvMode::None, &[]), none(), false),
+    ];
+    for (harness, options, lacking) in cases {
+        let notice = || notice_for(&harness, Capability::Headless, &options);
+        assert_eq!(notice().is_some(), lacking, "{:?}", harness.env);
+        let decisions = [(false, false), (true, false), (false, true), (true, true)]
+            .map(|(require, interactive)| decide(notice(), require, interactive));
+        match notice() {
+            None => assert!(decisions
+                .iter()
+                .all(|decision| *decision == Decision::Proceed)),
+            Some(text) => assert_eq!(
+                decisions,
+                [
+                    Decision::Warn(text.clone()),
+                    Decision::Refuse(text.clone()),
+                    Decision::Prompt(text.clone()),
+                    Decision::Prompt(text),
+                ]
+            ),
+        }
+    }
+}
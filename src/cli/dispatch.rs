@@ -1,8 +1,14 @@
-use super::{args::Action, compat, experimental, gate_cmd, guard, output};
+use super::{
+    args::Action, compat, completions, experimental, gate_cmd, guard, history, list, outdated,
+    output, preset, project_output, search, security_cmd,
+};
 use crate::context;
 use crate::contracts::{Capability, Harness};
 use std::path::Path;
 
+#[path = "dispatch_open.rs"]
+pub(super) mod open;
+
 pub fn dispatch(
     action: Action,
     harnesses: &[Harness],
@@ -10,15 +16,16 @@ pub fn dispatch(
     home: &Path,
 ) -> Result<(i32, String), String> {
     match action {
-        Action::List => Ok((0, output::list(harnesses))),
+        Action::List(words) => list::handle(&words, harnesses, home).map(|body| (0, body)),
+        Action::Search(words) => search::handle(&words, harnesses).map(|body| (0, body)),
         Action::Check => Ok((0, output::checks(harnesses))),
-        Action::Current => Ok((0, output::current(context::load(home).map_err(err)?))),
+        Action::Current => Ok((0, output::current(context::load(home).map_err(err)?, home))),
         Action::Use(name) => {
             find(harnesses, &name)?;
             context::save(home, &name).map_err(err)?;
             Ok((0, output::selected(&name)))
         }
-        Action::Show(name) => Ok((0, output::show(find(harnesses, &name)?))),
+        Action::Show { name, format } => Ok((0, output::show(find(harnesses, &name)?, format))),
         Action::Plan {
             harness,
             capability,
@@ -26,15 +33,19 @@ pub fn dispatch(
             let selected = selected_name(harness, home)?;
             Ok((0, output::plan(find(harnesses, &selected)?, capability)))
         }
-        Action::SelfUpdate { .. } => {
-            unreachable!("self-update handled before catalog load in execute()")
-        }
         Action::Run(words) => guard::run(&words, harnesses, home),
         Action::Direct { harness, extra } => guard::direct(&harness, &extra, harnesses, home),
         Action::Install(name) => guard::capability(harnesses, &name, Capability::Download, home),
+        Action::InstallPreset(words) => preset::install(&words, harnesses, home),
         Action::Update(Some(name)) => guard::capability(harnesses, &name, Capability::Update, home),
         Action::Update(None) => Ok((0, compat::update_summary(harnesses))),
+        Action::UpdateOutdated => outdated::update::run(harnesses, home),
+        Action::Outdated(words) => outdated::run(&words, harnesses),
+        Action::History(words) => history::handle(&words, home).map(|body| (0, body)),
         Action::Auth(words) => compat::auth(&words, harnesses).map(|body| (0, body)),
+        Action::Config(words) if words == ["show", "--effective"] => {
+            project_output::effective(harnesses, home).map(|body| (0, body))
+        }
         Action::Config(words) => compat::config(
             &words,
             catalog_root,
@@ -43,31 +54,19 @@ pub fn dispatch(
         )
         .map(|body| (0, body)),
         Action::Cache(words) => compat::cache(&words).map(|body| (0, body)),
-        Action::Security(words) => security(&words, harnesses),
+        Action::Security(words) => security_cmd::handle(&words, harnesses),
         Action::Gate(words) => gate_cmd::handle(&words, home),
         Action::Experimental(words) => {
             experimental::run(&words, harnesses, home).map(|body| (0, body))
         }
+        Action::Completions(words) => {
+            completions::handle(&words, harnesses, home).map(|body| (0, body))
+        }
         Action::Legacy(command) => Ok((0, compat::legacy(&command))),
         Action::Help => Ok((0, output::help())),
-        Action::Version { .. } => unreachable!("version is handled before catalog load"),
-    }
-}
-
-fn security(words: &[String], harnesses: &[Harness]) -> Result<(i32, String), String> {
-    match words {
-        [] => Ok((0, output::status(harnesses))),
-        [action] if action == "status" => Ok((0, output::status(harnesses))),
-        [action] if action == "audit" => Ok((0, output::audit(harnesses))),
-        [name] => Ok((
-            0,
-            output::plan(
-                find(harnesses, name)
-                    .map_err(|_| "usage: terminal-jarvis security [status|audit|harness]")?,
-                Capability::Security,
-            ),
-        )),
-        _ => Err("usage: terminal-jarvis security [status|audit|harness]".to_string()),
+        Action::Version { .. } | Action::SelfUpdate { .. } => {
+            unreachable!("version and self-update are handled before catalog load")
+        }
     }
 }
 
@@ -0,0 +1,13 @@
+use super::super::verbosity;
+use crate::{context, Jarvis};
+use std::path::Path;
+
+pub fn jarvis(catalog_root: &Path, home: &Path) -> Result<Jarvis, String> {
+    let setting = context::node_pm();
+    let jarvis =
+        Jarvis::open(catalog_root, home, setting.as_deref()).map_err(|error| error.to_string())?;
+    for warning in jarvis.warnings() {
+        verbosity::notice(&format!("warning: {warning}"));
+    }
+    Ok(jarvis)
+}
@@ -19,6 +19,8 @@ fn harness(name: &str) -> Harness {
         binary: name.to_string(),
         env_mode: EnvMode::None,
         env: vec![],
+        full_screen: false,
+        project_args: vec![],
         capabilities: Capability::ALL.iter().map(|c| cap(*c)).collect(),
     }
 }
@@ -30,7 +32,7 @@ fn paths() -> (&'static std::path::Path, &'static std::path::Path) {
 fn list_check_help_legacy() {
     let hs = [harness("opencode")];
     let (p, h) = paths();
-    assert_eq!(dispatch(Action::List, &hs, p, h).unwrap().0, 0);
+    assert_eq!(dispatch(Action::List(vec![]), &hs, p, h).unwrap().0, 0);
     assert_eq!(dispatch(Action::Check, &hs, p, h).unwrap().0, 0);
     assert_eq!(dispatch(Action::Help, &hs, p, h).unwrap().0, 0);
     let out = dispatch(Action::Legacy("templates".to_string()), &hs, p, h)
@@ -0,0 +1,51 @@
+use super::verbosity::Verbosity;
+
+const PRESENTATION: [&str; 5] = ["--plain", "--no-color", "--quiet", "-q", "--verbose"];
+
+pub fn presentation_args<I>(args: I) -> (Vec<String>, bool, bool, Verbosity)
+where
+    I: IntoIterator,
+    I::Item: Into<String>,
+{
+    let mut all = args.into_iter().map(Into::into).collect::<Vec<_>>();
+    let mut plain = false;
+    let mut no_color = false;
+    let mut level = Verbosity::Normal;
+    while all
+        .get(1)
+        .is_some_and(|word| PRESENTATION.contains(&word.as_str()))
+    {
+        match all.remove(1).as_str() {
+            "--plain" => plain = true,
+            "--no-color" => no_color = true,
+            "--quiet" | "-q" => level = Verbosity::Quiet,
+            _ => level = Verbosity::Verbose,
+        }
+    }
+    (all, plain, no_color, level)
+}
+
+pub fn root_args<I>(args: I) -> (Vec<String>, bool)
+where
+    I: IntoIterator,
+    I::Item: Into<String>,
+{
+    let mut all = args.into_iter().map(Into::into).collect::<Vec<_>>();
+    let command = all
+        .iter()
+        .skip(1)
+        .position(|word| word != "--allow-root" && !PRESENTATION.contains(&word.as_str()));
+    let installs = command
+        .and_then(|index| all.get(index + 1))
+        .is_some_and(|word| word == "install" || word == "update");
+    let mut index = 0;
+    let mut allowed = false;
+    all.retain(|word| {
+        index += 1;
+        let leading = command.is_none_or(|command| index <= command + 1);
+        let strip = word == "--allow-root" && index > 1 && (leading || installs);
+        allowed |= strip;
+        !strip
+    });
+    (all, allowed)
+}
@@ -1,12 +1,26 @@
-use super::{invoke, resolve};
-use crate::contracts::{Capability, Harness};
-use crate::gates;
+use super::{
+    credentials, invoke, launch, launch_log, path_hint, preinstall, print_env, project, resolve,
+};
+use crate::context::Launch;
+use crate::contracts::{Capability, Harness, LaunchOptions};
+use crate::{gates, security};
 use std::path::Path;
 
+#[path = "guard_workspace.rs"]
+mod workspace;
+
 pub fn run(words: &[String], harnesses: &[Harness], home: &Path) -> Result<(i32, String), String> {
-    let invocation = resolve::run(words, harnesses, home)?;
-    gates::preflight(home)?;
-    invoke::invocation(invocation, harnesses)
+    let (mut options, words) = launch::split(words)?;
+    let config = project::load(harnesses)?;
+    let preferred = config
+        .as_ref()
+        .and_then(|config| config.preferred.as_deref());
+    let mut invocation = resolve::run(&words, harnesses, home, preferred)?;
+    project::apply(config.as_ref(), &mut invocation, &mut options);
+    if options.print_env {
+        return print_env::render(harnesses, &invocation.harness, &options);
+    }
+    start(invocation, options, harnesses, home)
 }
 
 pub fn direct(
@@ -15,9 +29,36 @@ pub fn direct(
     harnesses: &[Harness],
     home: &Path,
 ) -> Result<(i32, String), String> {
-    let invocation = resolve::direct(name, extra, harnesses)?;
+    let mut invocation = resolve::direct(name, extra, harnesses)?;
+    let mut options = LaunchOptions::default();
+    project::apply(
+        project::load(harnesses)?.as_ref(),
+        &mut invocation,
+        &mut options,
+    );
+    start(invocation, options, harnesses, home)
+}
+
+pub fn start(
+    invocation: resolve::Invocation,
+    mut options: LaunchOptions,
+    harnesses: &[Harness],
+    home: &Path,
+) -> Result<(i32, String), String> {
     gates::preflight(home)?;
-    invoke::invocation(invocation, harnesses)
+    security::ArgLimits::from_env().check(&invocation.extra)?;
+    launch_log::prepare(&mut options, home, &invocation.harness)?;
+    credentials::check(harnesses, &invocation, &mut options)?;
+    let harness = harnesses
+        .iter()
+        .find(|harness| harness.name == invocation.harness);
+    workspace::check(harness, invocation.capability, &options);
+    let launched = Launch {
+        args: security::redact_args(&invocation.extra).join(" "),
+        ..Launch::new(&invocation.harness, invocation.capability.as_str())
+    };
+    invoke::invocation(invocation, harnesses, &options)
+        .map(|result| launch_log::finish(&options, home, launched, result))
 }
 
 pub fn capability(
@@ -26,25 +67,26 @@ pub fn capability(
     capability: Capability,
     home: &Path,
 ) -> Result<(i32, String), String> {
-    known(harnesses, name)?;
+    let harness = known(harnesses, name)?;
     gates::preflight(home)?;
-    invoke::capability(harnesses, name, capability, &[])
+    let download = capability == Capability::Download;
+    if download {
+        preinstall::check(harness, home)?;
+    }
+    let result = invoke::capability(harnesses, name, capability, &[], &LaunchOptions::default())?;
+    match download {
+        true => Ok(path_hint::after_install(harness, result)),
+        false => Ok(result),
+    }
 }
 
-fn known(harnesses: &[Harness], name: &str) -> Result<(), String> {
+fn known<'a>(harnesses: &'a [Harness], name: &str) -> Result<&'a Harness, String> {
     harnesses
         .iter()
-        .any(|harness| harness.name == name)
-        .then_some(())
+        .find(|harness| harness.name == name)
         .ok_or_else(|| format!("unknown harness '{name}'"))
 }
 
 #[cfg(test)]
-mod tests {
-    use super::known;
-
-    #[test]
-    fn unknown_harness_is_rejected() {
-        assert_eq!(known(&[], "ghost").unwrap_err(), "unknown harness 'ghost'");
-    }
-}
+#[path = "guard_test.rs"]
+mod tests;
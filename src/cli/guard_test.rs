@@ -0,0 +1,53 @@
+use super::*;
+
+#[test]
+fn unknown_harness_is_rejected() {
+    assert_eq!(known(&[], "ghost").unwrap_err(), "unknown harness 'ghost'");
+}
+
+fn harness(name: &str) -> Harness {
+    crate::catalog::load(Path::new("harnesses"))
+        .unwrap()
+        .into_iter()
+        .find(|harness| harness.name == name)
+        .unwrap()
+}
+
+fn temp(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("tj-workspace-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn missing_workspace_config_names_the_file_to_create() {
+    let (dir, kilocode) = (temp("notice"), harness("kilocode"));
+    let config = dir.join(".kilocode/config.json");
+    let notice = workspace::notice(&kilocode, &dir).unwrap();
+    assert!(
+        notice.contains(&format!("create {}", config.display())),
+        "{notice}"
+    );
+    std::fs::create_dir_all(config.parent().unwrap()).unwrap();
+    std::fs::write(&config, "{}").unwrap();
+    assert_eq!(workspace::notice(&kilocode, &dir), None);
+    assert_eq!(workspace::notice(&harness("codex"), &dir), None);
+}
+
+#[test]
+fn scaffold_writes_the_provider_from_present_keys_and_no_model() {
+    let present = |name: &str| name == "ANTHROPIC_API_KEY" || name == "OPENAI_API_KEY";
+    assert_eq!(workspace::provider(present), Some("anthropic"));
+    assert_eq!(
+        workspace::provider(|name| name == "OPENROUTER_API_KEY"),
+        Some("openrouter")
+    );
+    assert_eq!(workspace::provider(|_| false), None);
+    let dir = temp("scaffold");
+    let path = workspace::scaffold(&harness("kilocode"), &dir, "anthropic").unwrap();
+    assert_eq!(path, dir.join(".kilocode/config.json"));
+    let written = std::fs::read_to_string(path).unwrap();
+    assert_eq!(written, "{\n  \"provider\": \"anthropic\"\n}\n");
+    assert!(workspace::scaffold(&harness("codex"), &dir, "openai").is_err());
+}
@@ -0,0 +1,86 @@
+use super::super::{credential_prompt, style, verbosity};
+use crate::contracts::{Capability, Harness, LaunchOptions};
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+const CONFIGS: [(&str, &str); 1] = [("kilocode", ".kilocode/config.json")];
+const PROVIDERS: [(&str, &str); 3] = [
+    ("OPENROUTER_API_KEY", "openrouter"),
+    ("ANTHROPIC_API_KEY", "anthropic"),
+    ("OPENAI_API_KEY", "openai"),
+];
+
+pub fn check(harness: Option<&Harness>, capability: Capability, options: &LaunchOptions) {
+    let launches = matches!(
+        capability,
+        Capability::Ui | Capability::Headless | Capability::Yolo
+    );
+    let Some(harness) = harness.filter(|_| launches) else {
+        return;
+    };
+    let dir = options
+        .cwd
+        .clone()
+        .or_else(|| std::env::current_dir().ok())
+        .unwrap_or_default();
+    let Some(notice) = notice(harness, &dir) else {
+        return;
+    };
+    let present = |name: &str| options.provides(name) || std::env::var_os(name).is_some();
+    let interactive =
+        !options.stdin && std::io::stdin().is_terminal() && std::io::stderr().is_terminal();
+    match provider(present).filter(|_| interactive) {
+        Some(provider) => offer(harness, &dir, provider, &notice),
+        None => verbosity::notice(&style::warning(&notice)),
+    }
+}
+
+pub fn notice(harness: &Harness, dir: &Path) -> Option<String> {
+    let file = config_file(harness)?;
+    (!dir.join(file).exists()).then(|| {
+        format!(
+            "warning: {} reads its provider and model from {file} in the working directory, and {} has none; create {} to skip first-run setup",
+            harness.display,
+            dir.display(),
+            dir.join(file).display()
+        )
+    })
+}
+
+pub fn provider(present: impl Fn(&str) -> bool) -> Option<&'static str> {
+    PROVIDERS
+        .iter()
+        .find(|(name, _)| present(name))
+        .map(|(_, provider)| *provider)
+}
+
+pub fn scaffold(harness: &Harness, dir: &Path, provider: &str) -> Result<PathBuf, String> {
+    let file =
+        config_file(harness).ok_or_else(|| format!("{} has no workspace config", harness.name))?;
+    let path = dir.join(file);
+    let failed = |error: std::io::Error| format!("failed to write {}: {error}", path.display());
+    std::fs::create_dir_all(path.parent().unwrap_or(dir)).map_err(failed)?;
+    std::fs::write(&path, format!("{{\n  \"provider\": \"{provider}\"\n}}\n")).map_err(failed)?;
+    Ok(path)
+}
+
+fn offer(harness: &Harness, dir: &Path, provider: &str, notice: &str) {
+    eprintln!("{}", style::warning(notice));
+    eprint!("create a minimal config using provider {provider} and the default model? [Y/n]: ");
+    let _ = std::io::stderr().flush();
+    let answer = credential_prompt::read_line().unwrap_or_default();
+    if matches!(answer.trim(), "n" | "N" | "no") {
+        return;
+    }
+    match scaffold(harness, dir, provider) {
+        Ok(path) => verbosity::notice(&format!("created {}", path.display())),
+        Err(error) => eprintln!("{}", style::error(&error)),
+    }
+}
+
+fn config_file(harness: &Harness) -> Option<&'static str> {
+    CONFIGS
+        .iter()
+        .find(|(name, _)| *name == harness.name)
+        .map(|(_, file)| *file)
+}
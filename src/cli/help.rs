@@ -1,44 +1,5 @@
 use super::{style, table};
-const PLAIN: &str = "Terminal Jarvis\n\
-     Headless command center for coding-agent harnesses\n\n\
-     usage:\n\
-       terminal-jarvis [harness] [args...]\n\
-       terminal-jarvis run [harness] [capability] [args...]\n\
-       terminal-jarvis version [--verbose|--info|-v]\n\
-       terminal-jarvis list\n\
-       terminal-jarvis check\n\
-       terminal-jarvis use <harness>\n\
-       terminal-jarvis current\n\
-       terminal-jarvis show <harness>\n\
-       terminal-jarvis plan [harness] <capability>\n\
-       terminal-jarvis install <harness>\n\
-       terminal-jarvis update [harness]\n\
-       terminal-jarvis auth help <harness>\n\
-       terminal-jarvis config show\n\
-       terminal-jarvis cache status\n\
-       terminal-jarvis security [status|audit|harness]\n\
-       terminal-jarvis gate [status|list|enable [trivy]|disable|run [trivy]]\n\n\
-      global flags:\n\
-        --help, -h      show this help\n\
-        --version, -v   print the version (plain)\n\
-        --info          print version with provenance (same as version --verbose)\n\
-        --update [--dry-run]\n\
-                        self-update terminal-jarvis or print its package-manager command\n\
-        --plain         stable line-oriented output for automation\n\
-        --no-color      disable terminal color\n\n\
-      capabilities:\n\
-       download update headless version stats models security yolo ui\n\n\
-     examples:\n\
-       terminal-jarvis use opencode\n\
-       terminal-jarvis plan codex headless\n\
-       terminal-jarvis run opencode fix failing tests\n\
-       terminal-jarvis gate enable trivy\n\n\
-     experimental:\n\
-       TERMINAL_JARVIS_EXPERIMENTAL_UI=1 terminal-jarvis experimental dashboard\n\n\
-     legacy aliases:\n\
-       tools -> list, status -> check, info <harness> -> show <harness>\n\
-       install <harness> -> run <harness> download\n\
-       update <harness> -> run <harness> update\n";
+const PLAIN: &str = include_str!("help.txt");
 
 pub fn text() -> String {
     if style::plain() {
@@ -95,6 +56,34 @@ pub fn text() -> String {
             ),
         ],
     ));
+    out.push('\n');
+    out.push_str(&table::fields(
+        "Run Flags",
+        &[
+            (
+                "--TOOL-ENV KEY=VALUE",
+                "Set an env var for this launch only (repeatable)".into(),
+            ),
+            ("--CWD DIR", "Launch the harness in DIR".into()),
+            ("--STDIN", "Pass piped stdin through to the harness".into()),
+            (
+                "--SANDBOX",
+                "Launch inside docker/podman with only the working directory mounted".into(),
+            ),
+            (
+                "--LOG-STDERR",
+                "Also save the harness's stderr under <home>/logs".into(),
+            ),
+            (
+                "--LOG-TOOL-OUTPUT-TO FILE",
+                "Tee stdout and stderr to FILE; --quiet-tool-output hides them".into(),
+            ),
+            (
+                "--PRINT-ENV",
+                "Show the harness's variables, secrets redacted, instead of launching".into(),
+            ),
+        ],
+    ));
     out.push_str("\nExamples\n  terminal-jarvis use opencode\n  terminal-jarvis plan codex headless\n  terminal-jarvis gate enable trivy\n");
     out
 }
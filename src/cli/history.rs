@@ -0,0 +1,97 @@
+use super::{output, style, table};
+use crate::context::{self, Launch};
+use std::path::Path;
+
+pub const USAGE: &str = "usage: terminal-jarvis history [--tool NAME] [--limit N] [--json]";
+const LIMIT: usize = 20;
+
+pub fn handle(words: &[String], home: &Path) -> Result<String, String> {
+    let (mut tool, mut limit, mut json) = (None, LIMIT, false);
+    let mut words = words.iter();
+    while let Some(word) = words.next() {
+        match word.as_str() {
+            "--tool" => tool = Some(words.next().ok_or(USAGE)?.as_str()),
+            "--limit" => {
+                let count = words.next().and_then(|count| count.parse().ok());
+                limit = count.filter(|count| *count > 0).ok_or(USAGE)?;
+            }
+            "--json" => json = true,
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    let launches = context::history(home);
+    Ok(render(&select(&launches, tool, limit), json))
+}
+
+pub fn select<'a>(launches: &'a [Launch], tool: Option<&str>, limit: usize) -> Vec<&'a Launch> {
+    launches
+        .iter()
+        .rev()
+        .filter(|launch| tool.is_none_or(|tool| launch.harness == tool))
+        .take(limit)
+        .collect()
+}
+
+fn render(launches: &[&Launch], json: bool) -> String {
+    let number = |value: Option<String>| value.unwrap_or_else(|| "null".to_string());
+    if json {
+        let items = launches.iter().map(|launch| {
+            format!(
+                "{{\"at\":{},\"time\":\"{}\",\"tool\":{},\"capability\":{},\"args\":{},\"seconds\":{},\"exit\":{}}}",
+                launch.at,
+                context::iso8601(launch.at),
+                output::quote(&launch.harness),
+                output::quote(&launch.capability),
+                output::quote(&launch.args),
+                number(launch.seconds.map(|seconds| seconds.to_string())),
+                number(launch.code.map(|code| code.to_string()))
+            )
+        });
+        return format!("[{}]\n", items.collect::<Vec<_>>().join(","));
+    }
+    if launches.is_empty() {
+        return "no launches recorded yet\n".to_string();
+    }
+    let rows = launches
+        .iter()
+        .map(|launch| {
+            let code = launch.code.map(|code| code.to_string());
+            vec![
+                launch.age(),
+                launch.harness.clone(),
+                launch.capability.clone(),
+                launch.args.clone(),
+                launch
+                    .seconds
+                    .map(duration)
+                    .unwrap_or_else(|| "-".to_string()),
+                code.unwrap_or_else(|| "-".to_string()),
+            ]
+        })
+        .collect::<Vec<_>>();
+    if style::plain() {
+        return rows
+            .iter()
+            .map(|row| {
+                format!(
+                    "{} {} ({}, {}, exit {}): {}\n",
+                    row[1], row[2], row[0], row[4], row[5], row[3]
+                )
+            })
+            .collect();
+    }
+    let headers = ["WHEN", "TOOL", "CAPABILITY", "ARGS", "DURATION", "EXIT"];
+    table::render("Launch History", &headers, &rows)
+}
+
+fn duration(seconds: u64) -> String {
+    match seconds {
+        0..=59 => format!("{seconds}s"),
+        60..=3599 => format!("{}m {}s", seconds / 60, seconds % 60),
+        _ => format!("{}h {}m", seconds / 3600, seconds % 3600 / 60),
+    }
+}
+
+#[cfg(test)]
+#[path = "history_test.rs"]
+mod tests;
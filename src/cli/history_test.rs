@@ -0,0 +1,43 @@
+use super::*;
+
+fn launches() -> Vec<Launch> {
+    ["codex", "aider", "codex", "claude", "codex"]
+        .iter()
+        .enumerate()
+        .map(|(at, harness)| Launch {
+            at: at as u64,
+            ..Launch::new(harness, "ui")
+        })
+        .collect()
+}
+
+#[test]
+fn select_is_newest_first_and_respects_the_tool_filter_and_limit() {
+    let launches = launches();
+    let at = |selected: Vec<&Launch>| selected.iter().map(|launch| launch.at).collect::<Vec<_>>();
+    assert_eq!(at(select(&launches, None, 3)), [4, 3, 2]);
+    assert_eq!(at(select(&launches, Some("codex"), 10)), [4, 2, 0]);
+    assert_eq!(at(select(&launches, Some("codex"), 1)), [4]);
+    assert!(select(&launches, Some("pi"), 10).is_empty());
+}
+
+#[test]
+fn json_reports_unknown_outcomes_as_null() {
+    let launch = Launch {
+        at: 7,
+        code: Some(0),
+        args: "fix \"it\"".to_string(),
+        ..Launch::new("codex", "headless")
+    };
+    assert_eq!(
+        render(&[&launch], true),
+        "[{\"at\":7,\"time\":\"1970-01-01T00:00:07Z\",\"tool\":\"codex\",\"capability\":\"headless\",\"args\":\"fix \\\"it\\\"\",\"seconds\":null,\"exit\":0}]\n"
+    );
+}
+
+#[test]
+fn durations_use_the_two_largest_units() {
+    assert_eq!(duration(42), "42s");
+    assert_eq!(duration(192), "3m 12s");
+    assert_eq!(duration(3_900), "1h 5m");
+}
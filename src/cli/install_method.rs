@@ -0,0 +1,60 @@
+use std::process::{Command, Stdio};
+
+pub type Method = (&'static str, &'static [&'static str]);
+
+const NPM: Method = ("npm", &["install", "-g", "terminal-jarvis@latest"]);
+const CARGO: Method = ("cargo", &["install", "terminal-jarvis"]);
+const BREW: Method = ("brew", &["upgrade", "terminal-jarvis"]);
+
+pub fn detect() -> Result<Method, String> {
+    let distribution = std::env::var("TERMINAL_JARVIS_DISTRIBUTION").unwrap_or_default();
+    let path = std::env::current_exe()
+        .ok()
+        .map(|binary| binary.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let wrapper = crate::context::environment().wrapper.as_deref();
+    install_method(wrapper_path(wrapper).is_some(), &distribution, &path, npm_global_list).ok_or_else(|| {
+        format!("cannot tell how terminal-jarvis was installed (binary: {path}); update it with the package manager you used: `npm install -g terminal-jarvis@latest`, `brew upgrade terminal-jarvis`, or `cargo install terminal-jarvis`")
+    })
+}
+
+fn install_method<F>(wrapped: bool, distribution: &str, path: &str, npm_list: F) -> Option<Method>
+where
+    F: FnOnce() -> Option<String>,
+{
+    match distribution {
+        _ if wrapped => Some(NPM),
+        "github-release" | "github-release-cache" => Some(NPM),
+        "source" | "env" | "cargo" => Some(CARGO),
+        _ if homebrew_path(path) => Some(BREW),
+        _ if path.contains(".cargo") => Some(CARGO),
+        _ => npm_list()
+            .filter(|list| list.contains("terminal-jarvis@"))
+            .map(|_| NPM),
+    }
+}
+
+fn npm_global_list() -> Option<String> {
+    let output = Command::new("npm")
+        .args(["ls", "-g", "--depth=0", "terminal-jarvis"])
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    Some(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn homebrew_path(path: &str) -> bool {
+    path.contains("homebrew") || path.contains("Cellar")
+}
+
+fn wrapper_path(wrapper: Option<&str>) -> Option<std::path::PathBuf> {
+    let pkg = std::path::Path::new(wrapper?)
+        .parent()
+        .and_then(std::path::Path::parent)?
+        .join("package.json");
+    pkg.exists().then_some(pkg)
+}
+
+#[cfg(test)]
+#[path = "install_method_test.rs"]
+mod tests;
@@ -0,0 +1,53 @@
+use super::*;
+use std::fs;
+
+#[test]
+fn wrapper_path_requires_package_json() {
+    let base = std::env::temp_dir().join(format!("tjwrap-{}", std::process::id()));
+    let bin = base.join("bin");
+    fs::create_dir_all(&bin).unwrap();
+
+    assert!(wrapper_path(None).is_none());
+
+    let wrapper = bin.join("terminal-jarvis").display().to_string();
+    assert!(wrapper_path(Some(&wrapper)).is_none());
+
+    fs::write(base.join("package.json"), "{}").unwrap();
+    assert!(wrapper_path(Some(&wrapper)).is_some());
+
+    let _ = fs::remove_dir_all(&base);
+}
+
+#[test]
+fn homebrew_paths_cover_both_install_layouts() {
+    assert!(homebrew_path("/opt/homebrew/bin/terminal-jarvis"));
+    assert!(homebrew_path(
+        "/usr/local/Cellar/terminal-jarvis/0.1/bin/tj"
+    ));
+    assert!(!homebrew_path("/usr/local/bin/terminal-jarvis"));
+}
+
+#[test]
+fn distribution_and_binary_location_pick_the_package_manager() {
+    let unused = || panic!("npm is only asked as a last resort");
+    assert_eq!(install_method(true, "", "/x/tj", unused), Some(NPM));
+    assert_eq!(
+        install_method(false, "github-release-cache", "", unused),
+        Some(NPM)
+    );
+    assert_eq!(install_method(false, "source", "", unused), Some(CARGO));
+    let brew = "/opt/homebrew/bin/terminal-jarvis";
+    assert_eq!(install_method(false, "", brew, unused), Some(BREW));
+    let cargo = "/home/me/.cargo/bin/terminal-jarvis";
+    assert_eq!(install_method(false, "", cargo, unused), Some(CARGO));
+}
+
+#[test]
+fn npm_global_list_is_the_fallback_and_unknown_installs_are_refused() {
+    let path = "/usr/local/bin/terminal-jarvis";
+    let listed = || Some("/usr/lib\n`-- terminal-jarvis@0.1.12\n".to_string());
+    assert_eq!(install_method(false, "", path, listed), Some(NPM));
+    let empty = || Some("/usr/lib\n`-- (empty)\n".to_string());
+    assert_eq!(install_method(false, "", path, empty), None);
+    assert_eq!(install_method(false, "", path, || None), None);
+}
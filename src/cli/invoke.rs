@@ -1,16 +1,20 @@
-use super::resolve;
-use crate::contracts::{Capability, CommandPlan, Harness};
-use crate::runtime;
+use super::{path_hint, resolve, root, sandbox, verbosity};
+use crate::contracts::{Capability, CommandPlan, Harness, LaunchOptions};
+use crate::{runtime, security};
+
+const PREREQUISITES: &str = "https://github.com/BA-CalderonMorales/terminal-jarvis/blob/main/docs/supported-agents.md#installer-prerequisites";
 
 pub fn invocation(
     invocation: resolve::Invocation,
     harnesses: &[Harness],
+    options: &LaunchOptions,
 ) -> Result<(i32, String), String> {
     capability(
         harnesses,
         &invocation.harness,
         invocation.capability,
         &invocation.extra,
+        options,
     )
 }
 
@@ -19,22 +23,50 @@ pub fn capability(
     harness: &str,
     capability: Capability,
     extra: &[String],
+    options: &LaunchOptions,
 ) -> Result<(i32, String), String> {
-    let plan = find(harnesses, harness)?
+    let found = find(harnesses, harness)?;
+    let plan = found
         .plan(capability)
         .ok_or_else(|| format!("{harness} lacks {capability}"))?;
-    runtime::run_command(plan, extra)
+    let extra = plan.command.args_for(extra);
+    root::check(capability, &plan.command)?;
+    let missing = security::missing_requirements(&plan.command);
+    if !missing.is_empty() {
+        return Err(format!(
+            "{harness} {capability} requires {} on PATH; install it first (see {PREREQUISITES}) or run `terminal-jarvis plan {harness} {capability}` to review the command",
+            missing.join(", ")
+        ));
+    }
+    let options = &LaunchOptions {
+        inherit_stderr: found.full_screen,
+        ..options.clone()
+    };
+    let (launch, extra) = if options.sandbox {
+        (sandbox::plan(found, plan, extra, options)?, &[][..])
+    } else {
+        (plan.clone(), extra)
+    };
+    let rendered = format!("{} {}", launch.command.render(), extra.join(" "));
+    verbosity::detail(&format!("running: {}", rendered.trim_end()));
+    if let Some(dir) = &options.cwd {
+        verbosity::detail(&format!("working directory: {}", dir.display()));
+    }
+    runtime::run_command(&launch, extra, options)
         .map(|(code, output)| {
             if code == 0 {
-                (0, output)
-            } else {
-                (
-                    code,
-                    diagnostic(harness, capability, &plan.command, code, &output),
-                )
+                return (0, output);
+            }
+            let mut body = diagnostic(harness, capability, &launch.command, code, &output);
+            if let Some(hint) =
+                sandbox::hint(&plan.command.command, code, &output).filter(|_| options.sandbox)
+            {
+                body.truncate(body.trim_end().len());
+                body.push_str(&hint);
             }
+            (code, body)
         })
-        .map_err(|error| command_error(harness, plan.command.command.as_str(), error))
+        .map_err(|error| path_hint::command_error(harness, launch.command.command.as_str(), error))
 }
 
 fn diagnostic(
@@ -48,6 +80,7 @@ fn diagnostic(
     if output.contains("pipefail") || output.contains("Illegal option") {
         body.push_str("\n  hint: the script uses `set -o pipefail`, which `sh` (dash) does not support; set the harness command to `bash -c ...` in the registry.");
     }
+    body.extend(security::ownership_hint(output, &command.command));
     body
 }
 
@@ -58,13 +91,6 @@ fn find<'a>(harnesses: &'a [Harness], name: &str) -> Result<&'a Harness, String>
         .ok_or_else(|| format!("unknown harness '{name}'"))
 }
 
-fn command_error(harness: &str, binary: &str, error: std::io::Error) -> String {
-    if error.kind() == std::io::ErrorKind::NotFound {
-        return format!("{harness} binary '{binary}' was not found on PATH; run `terminal-jarvis install {harness}` or `terminal-jarvis plan {harness} download`");
-    }
-    error.to_string()
-}
-
 #[cfg(test)]
 #[path = "invoke_test.rs"]
 mod tests;
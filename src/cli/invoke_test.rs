@@ -9,6 +9,8 @@ fn fake_harness() -> Vec<Harness> {
         binary: "sh".into(),
         env_mode: EnvMode::None,
         env: vec![],
+        full_screen: false,
+        project_args: vec![],
         capabilities: vec![CapabilityPlan {
             capability: Capability::Download,
             summary: "d".into(),
@@ -19,7 +21,14 @@ fn fake_harness() -> Vec<Harness> {
 
 #[test]
 fn failing_command_diagnoses_harness_capability_and_exit() {
-    let (code, body) = capability(&fake_harness(), "vibe", Capability::Download, &[]).unwrap();
+    let (code, body) = capability(
+        &fake_harness(),
+        "vibe",
+        Capability::Download,
+        &[],
+        &LaunchOptions::default(),
+    )
+    .unwrap();
     assert_eq!(code, 3);
     assert!(body.contains("vibe"), "harness: {body}");
     assert!(body.contains("download"), "capability: {body}");
@@ -35,6 +44,8 @@ fn pipefail_harness() -> Vec<Harness> {
         binary: "sh".into(),
         env_mode: EnvMode::None,
         env: vec![],
+        full_screen: false,
+        project_args: vec![],
         capabilities: vec![CapabilityPlan {
             capability: Capability::Download,
             summary: "d".into(),
@@ -48,7 +59,14 @@ fn pipefail_harness() -> Vec<Harness> {
 
 #[test]
 fn failing_command_appends_pipefail_hint() {
-    let (code, body) = capability(&pipefail_harness(), "vibe", Capability::Download, &[]).unwrap();
+    let (code, body) = capability(
+        &pipefail_harness(),
+        "vibe",
+        Capability::Download,
+        &[],
+        &LaunchOptions::default(),
+    )
+    .unwrap();
     assert_eq!(code, 3);
     assert!(body.contains("pipefail"), "stderr not surfaced: {body}");
     assert!(
@@ -56,3 +74,20 @@ fn failing_command_appends_pipefail_hint() {
         "pipefail hint not appended: {body}"
     );
 }
+
+#[test]
+fn missing_prerequisite_blocks_before_running() {
+    let mut harnesses = fake_harness();
+    harnesses[0].capabilities[0].command =
+        CommandPlan::new("sh".into(), vec![]).with_requires(vec!["tj-missing-prerequisite".into()]);
+    let error = capability(
+        &harnesses,
+        "vibe",
+        Capability::Download,
+        &[],
+        &LaunchOptions::default(),
+    )
+    .unwrap_err();
+    assert!(error.contains("vibe download requires tj-missing-prerequisite on PATH"));
+    assert!(error.contains("docs/supported-agents.md#installer-prerequisites"));
+}
@@ -0,0 +1,81 @@
+use crate::contracts::LaunchOptions;
+use std::path::{Path, PathBuf};
+
+const USAGE: &str = "usage: terminal-jarvis run [--tool-env KEY=VALUE]... [--cwd DIR] [--stdin] [--sandbox] [--log-stderr] [--log-tool-output-to FILE [--quiet-tool-output]] [--print-env] [--require-auth] [harness] [capability] [args...]";
+const VALUED: [&str; 3] = ["--tool-env", "--cwd", "--log-tool-output-to"];
+
+pub fn split(words: &[String]) -> Result<(LaunchOptions, Vec<String>), String> {
+    let mut options = LaunchOptions::default();
+    let mut index = 0;
+    while let Some(word) = words.get(index) {
+        if let Some(flag) = switch(&mut options, word) {
+            *flag = true;
+            index += 1;
+            continue;
+        }
+        let (name, inline) = word
+            .split_once('=')
+            .map_or((word.as_str(), None), |(name, value)| (name, Some(value)));
+        if !VALUED.contains(&name) {
+            break;
+        }
+        let value = match inline {
+            Some(value) => value,
+            None => {
+                index += 1;
+                words.get(index).ok_or_else(|| USAGE.to_string())?
+            }
+        };
+        match name {
+            "--tool-env" => options.env.push(env_pair(value)?),
+            "--cwd" => options.cwd = Some(directory(value)?),
+            _ => options.output_log = Some(PathBuf::from(value)),
+        }
+        index += 1;
+    }
+    if options.quiet_output && options.output_log.is_none() {
+        return Err("--quiet-tool-output needs --log-tool-output-to FILE".to_string());
+    }
+    Ok((options, words[index..].to_vec()))
+}
+
+fn switch<'a>(options: &'a mut LaunchOptions, word: &str) -> Option<&'a mut bool> {
+    match word {
+        "--stdin" => Some(&mut options.stdin),
+        "--sandbox" => Some(&mut options.sandbox),
+        "--log-stderr" => Some(&mut options.log_stderr),
+        "--quiet-tool-output" => Some(&mut options.quiet_output),
+        "--print-env" => Some(&mut options.print_env),
+        "--require-auth" => Some(&mut options.require_auth),
+        _ => None,
+    }
+}
+
+fn directory(dir: &str) -> Result<PathBuf, String> {
+    Path::new(dir)
+        .canonicalize()
+        .ok()
+        .filter(|path| path.is_dir())
+        .ok_or_else(|| format!("--cwd '{dir}' is not an existing directory"))
+}
+
+fn env_pair(value: &str) -> Result<(String, String), String> {
+    let (key, value) = value
+        .split_once('=')
+        .ok_or_else(|| format!("--tool-env expects KEY=VALUE, got '{value}'"))?;
+    let mut chars = key.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_');
+    if !valid {
+        return Err(format!(
+            "--tool-env key '{key}' is not a valid variable name"
+        ));
+    }
+    Ok((key.to_string(), value.to_string()))
+}
+
+#[cfg(test)]
+#[path = "launch_test.rs"]
+mod tests;
@@ -0,0 +1,55 @@
+use super::verbosity;
+use crate::context::{self, Launch};
+use crate::contracts::LaunchOptions;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+pub fn prepare(options: &mut LaunchOptions, home: &Path, harness: &str) -> Result<(), String> {
+    let by_default = std::env::var("TERMINAL_JARVIS_LOG_STDERR").is_ok_and(|value| value == "1");
+    if options.log_stderr || by_default {
+        let path = context::stderr_log(home, harness)
+            .map_err(|error| format!("cannot create the stderr log: {error}"))?;
+        options.stderr_log = Some(path);
+    }
+    if let Some(path) = &options.output_log {
+        File::create(path)
+            .map_err(|error| format!("cannot write '{}': {error}", path.display()))?;
+    }
+    Ok(())
+}
+
+pub fn finish(
+    options: &LaunchOptions,
+    home: &Path,
+    launch: Launch,
+    (code, mut body): (i32, String),
+) -> (i32, String) {
+    if let Some(path) = &options.stderr_log {
+        verbosity::notice(&format!("stderr log: {}", path.display()));
+    }
+    if let Some(path) = &options.output_log {
+        verbosity::notice(&format!("tool output log: {}", path.display()));
+    }
+    let harness = launch.harness.clone();
+    if matches!(launch.capability.as_str(), "ui" | "headless" | "yolo") {
+        let launch = Launch {
+            code: Some(code),
+            seconds: Some(context::now().saturating_sub(launch.at)),
+            ..launch
+        };
+        let _ = context::record_launch(home, &launch);
+    }
+    if code != 0 && options.stderr_log.is_none() {
+        if let Some(path) = save(home, &harness, &body) {
+            body.truncate(body.trim_end().len());
+            body.push_str(&format!("\n  saved: {}\n", path.display()));
+        }
+    }
+    (code, body)
+}
+
+fn save(home: &Path, harness: &str, body: &str) -> Option<PathBuf> {
+    let path = context::stderr_log(home, harness).ok()?;
+    fs::write(&path, body).ok()?;
+    Some(path)
+}
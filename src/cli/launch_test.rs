@@ -0,0 +1,91 @@
+use super::*;
+
+fn words(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+#[test]
+fn leading_tool_env_flags_are_collected_in_order() {
+    let (options, rest) = split(&words(&[
+        "--tool-env",
+        "A=1",
+        "--tool-env=B_2=x=y",
+        "codex",
+        "--tool-env",
+        "C=3",
+    ]))
+    .unwrap();
+    assert_eq!(
+        options.env,
+        [("A".into(), "1".into()), ("B_2".into(), "x=y".into())]
+    );
+    assert_eq!(rest, words(&["codex", "--tool-env", "C=3"]));
+}
+
+#[test]
+fn empty_values_are_allowed() {
+    let (options, _) = split(&words(&["--tool-env", "DEBUG="])).unwrap();
+    assert_eq!(options.env, [("DEBUG".into(), String::new())]);
+}
+
+#[test]
+fn malformed_pairs_are_rejected() {
+    assert!(split(&words(&["--tool-env", "NOVALUE"]))
+        .unwrap_err()
+        .contains("KEY=VALUE"));
+    assert!(split(&words(&["--tool-env", "1BAD=x"]))
+        .unwrap_err()
+        .contains("not a valid variable name"));
+    assert!(split(&words(&["--tool-env", "=x"])).is_err());
+    assert!(split(&words(&["--tool-env"]))
+        .unwrap_err()
+        .contains("usage"));
+}
+
+#[test]
+fn stdin_flag_is_consumed_before_the_harness() {
+    let (options, rest) = split(&words(&["--stdin", "claude", "--stdin"])).unwrap();
+    assert!(options.stdin);
+    assert_eq!(rest, words(&["claude", "--stdin"]));
+    assert!(!split(&words(&["claude"])).unwrap().0.stdin);
+}
+
+#[test]
+fn log_stderr_flag_is_consumed_before_the_harness() {
+    let (options, rest) = split(&words(&["--log-stderr", "codex", "--log-stderr"])).unwrap();
+    assert!(options.log_stderr);
+    assert_eq!(options.stderr_log, None);
+    assert_eq!(rest, words(&["codex", "--log-stderr"]));
+}
+
+#[test]
+fn cwd_must_name_an_existing_directory() {
+    let dir = std::env::temp_dir();
+    let (options, rest) = split(&words(&["--cwd", dir.to_str().unwrap(), "pi"])).unwrap();
+    assert_eq!(options.cwd, Some(dir.canonicalize().unwrap()));
+    assert_eq!(rest, words(&["pi"]));
+    let inline = format!("--cwd={}", dir.display());
+    assert!(split(&words(&[&inline])).unwrap().0.cwd.is_some());
+    let missing = split(&words(&["--cwd", "/no/such/tj-dir", "pi"])).unwrap_err();
+    assert!(missing.contains("is not an existing directory"));
+    assert!(split(&words(&["--cwd"])).unwrap_err().starts_with("usage:"));
+}
+
+#[test]
+fn tool_output_log_takes_a_path_and_quiet_needs_it() {
+    let (options, rest) = split(&words(&[
+        "--log-tool-output-to",
+        "out.log",
+        "--quiet-tool-output",
+        "codex",
+    ]))
+    .unwrap();
+    assert_eq!(options.output_log, Some(PathBuf::from("out.log")));
+    assert!(options.quiet_output);
+    assert_eq!(rest, words(&["codex"]));
+    let inline = split(&words(&["--log-tool-output-to=x.log"])).unwrap().0;
+    assert_eq!(inline.output_log, Some(PathBuf::from("x.log")));
+    assert!(split(&words(&["--quiet-tool-output", "codex"]))
+        .unwrap_err()
+        .contains("needs --log-tool-output-to"));
+}
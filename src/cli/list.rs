@@ -0,0 +1,95 @@
+use super::{output, project, recent, verbosity};
+use crate::context;
+use crate::contracts::Harness;
+use crate::security;
+use std::path::Path;
+
+const USAGE: &str = "usage: terminal-jarvis list [--sort name|status|recent] [--desc]";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum SortKey {
+    Catalog,
+    Name,
+    Status,
+    Recent,
+}
+
+pub fn handle(words: &[String], harnesses: &[Harness], home: &Path) -> Result<String, String> {
+    let (key, descending) = parse(words)?;
+    let installed = |harness: &Harness| security::command_on_path(&harness.binary);
+    let sorted = match key {
+        SortKey::Recent => {
+            recent::recency(harnesses, &context::history(home), descending, installed)
+        }
+        _ => sort(harnesses, key, descending, output::is_harness_ready),
+    };
+    Ok(output::list(&mark_project(sorted)))
+}
+
+fn mark_project(mut harnesses: Vec<Harness>) -> Vec<Harness> {
+    let config = project::load(&harnesses).unwrap_or_else(|error| {
+        verbosity::notice(&format!("warning: ignoring project config: {error}"));
+        None
+    });
+    let Some(config) = config else {
+        return harnesses;
+    };
+    for harness in &mut harnesses {
+        let preferred = config.preferred.as_ref() == Some(&harness.name);
+        if preferred || config.tools.contains_key(&harness.name) {
+            harness.description.push_str(" (project)");
+        }
+    }
+    harnesses
+}
+
+fn parse(words: &[String]) -> Result<(SortKey, bool), String> {
+    let mut key = SortKey::Catalog;
+    let mut descending = false;
+    let mut words = words.iter();
+    while let Some(word) = words.next() {
+        let value = match word.as_str() {
+            "--desc" => {
+                descending = true;
+                continue;
+            }
+            "--sort" => words.next().ok_or_else(|| USAGE.to_string())?.as_str(),
+            other => other
+                .strip_prefix("--sort=")
+                .ok_or_else(|| USAGE.to_string())?,
+        };
+        key = match value {
+            "name" => SortKey::Name,
+            "status" => SortKey::Status,
+            "recent" => SortKey::Recent,
+            other => {
+                return Err(format!(
+                    "unknown sort key '{other}'; expected name, status, or recent"
+                ))
+            }
+        };
+    }
+    Ok((key, descending))
+}
+
+fn sort<F>(harnesses: &[Harness], key: SortKey, descending: bool, ready: F) -> Vec<Harness>
+where
+    F: Fn(&Harness) -> bool,
+{
+    let mut sorted = harnesses.to_vec();
+    match key {
+        SortKey::Catalog | SortKey::Recent => {}
+        SortKey::Name => sorted.sort_by(|left, right| left.name.cmp(&right.name)),
+        SortKey::Status => {
+            sorted.sort_by_cached_key(|harness| (!ready(harness), harness.name.clone()))
+        }
+    }
+    if descending {
+        sorted.reverse();
+    }
+    sorted
+}
+
+#[cfg(test)]
+#[path = "list_test.rs"]
+mod tests;
@@ -0,0 +1,80 @@
+use super::*;
+use crate::contracts::EnvMode;
+
+fn harness(name: &str) -> Harness {
+    Harness {
+        name: name.to_string(),
+        display: name.to_string(),
+        description: String::new(),
+        binary: name.to_string(),
+        env_mode: EnvMode::None,
+        env: vec![],
+        full_screen: false,
+        project_args: vec![],
+        capabilities: vec![],
+    }
+}
+
+fn names(harnesses: &[Harness]) -> Vec<&str> {
+    harnesses
+        .iter()
+        .map(|harness| harness.name.as_str())
+        .collect()
+}
+
+fn words(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+#[test]
+fn name_sort_is_lexicographic_and_reversible() {
+    let hs = [harness("pi"), harness("aider"), harness("codex")];
+    let ready = |_: &Harness| true;
+    assert_eq!(
+        names(&sort(&hs, SortKey::Catalog, false, ready)),
+        ["pi", "aider", "codex"]
+    );
+    assert_eq!(
+        names(&sort(&hs, SortKey::Name, false, ready)),
+        ["aider", "codex", "pi"]
+    );
+    assert_eq!(
+        names(&sort(&hs, SortKey::Name, true, ready)),
+        ["pi", "codex", "aider"]
+    );
+}
+
+#[test]
+fn status_sort_puts_ready_harnesses_first_then_sorts_by_name() {
+    let hs = [
+        harness("pi"),
+        harness("qwen"),
+        harness("aider"),
+        harness("codex"),
+    ];
+    let ready = |harness: &Harness| harness.name == "qwen" || harness.name == "codex";
+    let sorted = sort(&hs, SortKey::Status, false, ready);
+    assert_eq!(names(&sorted), ["codex", "qwen", "aider", "pi"]);
+}
+
+#[test]
+fn parse_accepts_both_flag_forms() {
+    assert_eq!(parse(&[]).unwrap(), (SortKey::Catalog, false));
+    assert_eq!(
+        parse(&words(&["--sort", "name"])).unwrap(),
+        (SortKey::Name, false)
+    );
+    assert_eq!(
+        parse(&words(&["--desc", "--sort=status"])).unwrap(),
+        (SortKey::Status, true)
+    );
+    assert_eq!(
+        parse(&words(&["--sort", "recent"])).unwrap(),
+        (SortKey::Recent, false)
+    );
+    assert!(parse(&words(&["--sort"])).is_err());
+    assert!(parse(&words(&["--sort", "category"]))
+        .unwrap_err()
+        .contains("expected name, status, or recent"));
+    assert!(parse(&words(&["extra"])).is_err());
+}
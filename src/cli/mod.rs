@@ -1,21 +1,46 @@
 mod action;
 pub mod args;
+mod auth_list;
 mod cache;
 mod compat;
 mod compat_support;
+mod completion_install;
+mod completion_scripts;
+mod completions;
+mod config_validate;
+mod credential_prompt;
+mod credentials;
 mod dispatch;
 mod experimental;
 mod gate_cmd;
-mod guard;
+mod global;
+pub(crate) mod guard;
 mod help;
+mod history;
+mod install_method;
 mod invoke;
+mod launch;
+mod launch_log;
+mod list;
+mod outdated;
 mod output;
-mod resolve;
+mod path_hint;
+mod preinstall;
+mod preset;
+mod print_env;
+mod project;
+mod project_output;
+mod recent;
+pub(crate) mod resolve;
+mod root;
+mod sandbox;
+mod search;
+mod security_cmd;
 mod self_update;
 mod style;
 mod table;
+pub(crate) mod verbosity;
 mod version;
-use crate::catalog;
 use args::Action;
 use std::path::Path;
 
@@ -24,8 +49,11 @@ where
     I: IntoIterator,
     I::Item: Into<String>,
 {
-    let (args, plain, no_color) = presentation_args(args);
-    let previous = style::set(plain, no_color);
+    let (args, allowed) = global::root_args(args);
+    let previous_root = crate::security::allow_root(allowed);
+    let (args, plain, no_color, level) = global::presentation_args(args);
+    let previous = style::set(plain || style::dumb_terminal(), no_color);
+    let previous_level = verbosity::set(level);
     let result = execute(args, catalog_root, home);
     let code = match result {
         Ok((code, body)) => {
@@ -40,28 +68,11 @@ where
         }
     };
     style::restore(previous);
+    verbosity::set(previous_level);
+    crate::security::allow_root(previous_root);
     code
 }
 
-fn presentation_args<I>(args: I) -> (Vec<String>, bool, bool)
-where
-    I: IntoIterator,
-    I::Item: Into<String>,
-{
-    let mut all = args.into_iter().map(Into::into).collect::<Vec<_>>();
-    let mut plain = false;
-    let mut no_color = false;
-    while all
-        .get(1)
-        .is_some_and(|word| word == "--plain" || word == "--no-color")
-    {
-        let flag = all.remove(1);
-        plain |= flag == "--plain";
-        no_color |= flag == "--no-color";
-    }
-    (all, plain, no_color)
-}
-
 fn execute<I>(args: I, catalog_root: &Path, home: &Path) -> Result<(i32, String), String>
 where
     I: IntoIterator,
@@ -77,24 +88,12 @@ where
     if let Action::SelfUpdate { dry_run } = action {
         return self_update::run(dry_run);
     }
-    let harnesses =
-        catalog::load(catalog_root).map_err(|error| catalog_error(catalog_root, error))?;
-    let errors = catalog::validate(&harnesses);
-    if !errors.is_empty() {
-        return Err(errors.join("; "));
-    }
-    dispatch::dispatch(action, &harnesses, catalog_root, home)
-}
-
-fn catalog_error(path: &Path, error: std::io::Error) -> String {
-    if error.kind() == std::io::ErrorKind::NotFound {
-        return format!(
-            "harness catalog is missing at {}; reinstall terminal-jarvis or set TERMINAL_JARVIS_CATALOG",
-            path.display()
-        );
+    if let Action::Config(words) = &action {
+        if words.first().is_some_and(|word| word == "validate") {
+            return config_validate::run(&words[1..], catalog_root);
+        }
     }
-    format!(
-        "failed to load harness catalog at {}: {error}",
-        path.display()
-    )
+    verbosity::detail(&format!("catalog: {}", catalog_root.display()));
+    let jarvis = dispatch::open::jarvis(catalog_root, home)?;
+    dispatch::dispatch(action, jarvis.harnesses(), catalog_root, home)
 }
@@ -1,17 +1,22 @@
-use super::super::{experimental, presentation_args};
+use super::super::experimental;
+use super::super::global::presentation_args;
+use super::super::verbosity::Verbosity;
 
 #[test]
 fn presentation_flags_are_removed_and_accumulated() {
-    let (args, plain, no_color) = presentation_args(["tj", "--plain", "--no-color", "list"]);
+    let (args, plain, no_color, level) = presentation_args(["tj", "--plain", "--no-color", "list"]);
     assert_eq!(args, ["tj", "list"]);
     assert!(plain);
     assert!(no_color);
-    let (_, plain, no_color) = presentation_args(["tj", "--plain", "list"]);
+    assert_eq!(level, Verbosity::Normal);
+    let (_, plain, no_color, level) = presentation_args(["tj", "--plain", "-q", "list"]);
     assert!(plain);
     assert!(!no_color);
-    let (_, plain, no_color) = presentation_args(["tj", "--no-color", "list"]);
+    assert_eq!(level, Verbosity::Quiet);
+    let (_, plain, no_color, level) = presentation_args(["tj", "--no-color", "--verbose", "list"]);
     assert!(!plain);
     assert!(no_color);
+    assert_eq!(level, Verbosity::Verbose);
 }
 
 #[test]
@@ -20,3 +25,39 @@ fn experimental_rejects_unknown_actions() {
     let error = experimental::run(&words, &[], std::path::Path::new("/missing")).unwrap_err();
     assert_eq!(error, "usage: terminal-jarvis experimental dashboard");
 }
+
+#[test]
+fn allow_root_is_taken_from_leading_flags_and_install_commands() {
+    use super::super::global::root_args;
+
+    let root = |args: &[&str]| root_args(args.iter().copied());
+    assert_eq!(
+        root(&["tj", "--allow-root", "--plain", "run", "x"]),
+        (
+            vec!["tj".into(), "--plain".into(), "run".into(), "x".into()],
+            true
+        )
+    );
+    let stripped = |args: &[&str]| root(args).0.join(" ");
+    assert_eq!(
+        stripped(&["tj", "install", "codex", "--allow-root"]),
+        "tj install codex"
+    );
+    assert_eq!(
+        stripped(&["tj", "update", "--allow-root", "codex"]),
+        "tj update codex"
+    );
+    let passed = root(&["tj", "run", "codex", "--allow-root"]);
+    assert_eq!(
+        passed,
+        (
+            vec![
+                "tj".into(),
+                "run".into(),
+                "codex".into(),
+                "--allow-root".into()
+            ],
+            false
+        )
+    );
+}
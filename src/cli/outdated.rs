@@ -0,0 +1,100 @@
+use super::{output, style, table};
+use crate::contracts::{Capability, Harness};
+use crate::{runtime, security};
+use std::cmp::Ordering;
+use std::time::Duration;
+#[path = "outdated_update.rs"]
+pub(super) mod update;
+
+pub const USAGE: &str = "usage: terminal-jarvis outdated [--only-outdated] [--json]";
+const JOBS: usize = 6;
+const TIMEOUT: Duration = Duration::from_secs(8);
+const HEADERS: [&str; 4] = ["TOOL", "INSTALLED", "LATEST", "STATUS"];
+
+pub fn run(words: &[String], harnesses: &[Harness]) -> Result<(i32, String), String> {
+    let (mut only_outdated, mut json) = (false, false);
+    for word in words {
+        match word.as_str() {
+            "--only-outdated" => only_outdated = true,
+            "--json" => json = true,
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    let installed = harnesses
+        .iter()
+        .filter(|harness| security::command_on_path(&harness.binary))
+        .collect::<Vec<_>>();
+    let rows = runtime::run_bounded(&installed, JOBS, |harness| {
+        let latest = runtime::source(harness).and_then(|source| runtime::latest(&source, TIMEOUT));
+        row(&harness.name, installed_version(harness), latest)
+    });
+    let outdated = rows.iter().filter(|row| row[3] == "outdated").count();
+    let rows = rows
+        .into_iter()
+        .filter(|row| !only_outdated || row[3] == "outdated")
+        .collect::<Vec<_>>();
+    Ok((i32::from(outdated > 0), render(&rows, outdated, json)))
+}
+
+pub fn row(name: &str, installed: Option<String>, latest: Option<String>) -> Vec<String> {
+    let status = match (installed.as_deref(), latest.as_deref()) {
+        (Some(installed), Some(latest)) => match runtime::compare(installed, latest) {
+            Some(Ordering::Less) => "outdated",
+            Some(_) => "current",
+            None => "unknown",
+        },
+        _ => "unknown",
+    };
+    let known = |version: Option<String>| version.unwrap_or_else(|| "?".to_string());
+    vec![
+        name.to_string(),
+        known(installed),
+        known(latest),
+        status.to_string(),
+    ]
+}
+
+fn installed_version(harness: &Harness) -> Option<String> {
+    let plan = harness.plan(Capability::Version)?;
+    runtime::capture(&plan.command, TIMEOUT).and_then(|text| runtime::parse_version(&text))
+}
+
+fn render(rows: &[Vec<String>], outdated: usize, json: bool) -> String {
+    if json {
+        let value = |cell: &String| match cell.as_str() {
+            "?" => "null".to_string(),
+            cell => output::quote(cell),
+        };
+        let items = rows
+            .iter()
+            .map(|row| {
+                let fields = HEADERS.iter().zip(row).map(|(header, cell)| {
+                    format!("\"{}\":{}", header.to_ascii_lowercase(), value(cell))
+                });
+                format!("{{{}}}", fields.collect::<Vec<_>>().join(","))
+            })
+            .collect::<Vec<_>>();
+        return format!("[{}]\n", items.join(","));
+    }
+    let total = format!("{outdated} outdated");
+    if style::plain() {
+        let mut out = rows
+            .iter()
+            .map(|row| format!("{}: {} -> {} ({})\n", row[0], row[1], row[2], row[3]))
+            .collect::<String>();
+        out.push_str(&format!("{total}\n"));
+        return out;
+    }
+    let mut out = table::render(&format!("Outdated Tools ({total})"), &HEADERS, rows);
+    if outdated > 0 {
+        out.push_str(&style::warning(
+            "run `terminal-jarvis update <harness>` to update a tool",
+        ));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+#[path = "outdated_test.rs"]
+mod tests;
@@ -0,0 +1,57 @@
+use super::*;
+
+fn known(version: &str) -> Option<String> {
+    Some(version.to_string())
+}
+
+#[test]
+fn rows_compare_installed_against_latest() {
+    assert_eq!(
+        row("codex", known("0.45.0"), known("0.46.0"))[3],
+        "outdated"
+    );
+    assert_eq!(row("aider", known("0.86.1"), known("0.86.1"))[3], "current");
+    assert_eq!(row("pi", known("2.0.0"), known("2.0.0-rc.1"))[3], "current");
+    assert_eq!(row("goose", known("nightly"), known("1.9.0"))[3], "unknown");
+    assert_eq!(
+        row("claude", known("1.0.0"), None),
+        ["claude", "1.0.0", "?", "unknown"]
+    );
+}
+
+#[test]
+fn json_uses_null_for_unknown_versions() {
+    let rows = [row("claude", known("1.0.0"), None)];
+    assert_eq!(
+        render(&rows, 0, true),
+        "[{\"tool\":\"claude\",\"installed\":\"1.0.0\",\"latest\":null,\"status\":\"unknown\"}]\n"
+    );
+}
+
+#[test]
+fn plain_output_lists_each_tool_and_the_outdated_count() {
+    let previous = style::set(true, true);
+    let rows = [row("codex", known("0.45.0"), known("0.46.0"))];
+    assert_eq!(
+        render(&rows, 1, false),
+        "codex: 0.45.0 -> 0.46.0 (outdated)\n1 outdated\n"
+    );
+    style::restore(previous);
+}
+
+#[test]
+fn only_outdated_updates_select_tools_known_to_be_behind() {
+    let rows = [
+        row("codex", known("0.45.0"), known("0.46.0")),
+        row("aider", known("0.86.1"), known("0.86.1")),
+        row("pi", known("2.1.0"), known("2.0.0")),
+        row("claude", known("1.0.0"), None),
+        row("goose", None, known("1.9.0")),
+    ];
+    let selected = rows
+        .iter()
+        .filter(|row| update::stale(row))
+        .map(|row| row[0].as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(selected, ["codex"]);
+}
@@ -0,0 +1,59 @@
+use super::super::{invoke, style, table};
+use super::{installed_version, row, JOBS, TIMEOUT};
+use crate::contracts::{Capability, Harness, LaunchOptions};
+use crate::{gates, runtime, security};
+use std::path::Path;
+
+const HEADERS: [&str; 4] = ["TOOL", "INSTALLED", "LATEST", "RESULT"];
+
+pub fn run(harnesses: &[Harness], home: &Path) -> Result<(i32, String), String> {
+    gates::preflight(home)?;
+    let installed = harnesses
+        .iter()
+        .filter(|harness| security::command_on_path(&harness.binary))
+        .collect::<Vec<_>>();
+    let checked = runtime::run_bounded(&installed, JOBS, |harness| {
+        let latest = runtime::source(harness).and_then(|source| runtime::latest(&source, TIMEOUT));
+        row(&harness.name, installed_version(harness), latest)
+    });
+    let results = runtime::run_bounded(&checked, JOBS, |row| match row[3].as_str() {
+        _ if stale(row) => update(harnesses, &row[0]),
+        "current" => ("up to date".to_string(), None),
+        _ => ("skipped".to_string(), None),
+    });
+    let mut rows = Vec::new();
+    for (mut row, (result, error)) in checked.into_iter().zip(results) {
+        error.inspect(|error| eprintln!("{}", style::error(error)));
+        row[3] = result;
+        rows.push(row);
+    }
+    let count = |result: &str| rows.iter().filter(|row| row[3] == result).count();
+    let (updated, current, skipped) = (count("updated"), count("up to date"), count("skipped"));
+    let code = i32::from(updated + current + skipped < rows.len());
+    let mut total = format!("{updated} updated, {current} up to date");
+    if skipped > 0 {
+        total.push_str(&format!(", {skipped} skipped (version unknown)"));
+    }
+    if style::plain() {
+        let mut out = rows
+            .iter()
+            .map(|row| format!("{}: {} -> {} ({})\n", row[0], row[1], row[2], row[3]))
+            .collect::<String>();
+        out.push_str(&format!("{total}\n"));
+        return Ok((code, out));
+    }
+    let title = format!("Update Outdated ({total})");
+    Ok((code, table::render(&title, &HEADERS, &rows)))
+}
+
+pub fn stale(row: &[String]) -> bool {
+    row[3] == "outdated"
+}
+
+fn update(harnesses: &[Harness], name: &str) -> (String, Option<String>) {
+    let options = LaunchOptions::default();
+    match invoke::capability(harnesses, name, Capability::Update, &[], &options) {
+        Ok((0, _)) => ("updated".to_string(), None),
+        Ok((_, output)) | Err(output) => ("failed".to_string(), Some(output)),
+    }
+}
@@ -1,40 +1,25 @@
 #[path = "output_catalog.rs"]
 mod catalog;
+#[path = "output_session.rs"]
+mod session;
+#[path = "output_show.rs"]
+mod show;
 #[path = "output_summary.rs"]
 mod summary;
 
 use super::{style, table};
 use crate::contracts::Harness;
-use crate::{context::Session, security};
+use crate::security;
 
-pub use catalog::{list, plan, show};
+pub use catalog::{list, plan};
+pub use session::{current, selected};
+pub use show::{quote, show};
 pub use summary::{audit, status};
 
 pub fn help() -> String {
     super::help::text()
 }
 
-pub fn current(session: Option<Session>) -> String {
-    let active = session
-        .map(|session| session.active_harness)
-        .unwrap_or_else(|| "none".to_string());
-    if style::plain() {
-        return format!("active harness = {active}\n");
-    }
-    table::fields("Active Harness", &[("HARNESS", active)])
-}
-
-pub fn selected(name: &str) -> String {
-    if style::plain() {
-        return format!("active harness = {name}\n");
-    }
-    format!(
-        "{}\n{}",
-        style::success("Active harness updated"),
-        table::fields("Active Harness", &[("HARNESS", name.to_string())])
-    )
-}
-
 pub fn checks(harnesses: &[Harness]) -> String {
     if style::plain() {
         return plain_checks(harnesses);
@@ -36,6 +36,7 @@ pub fn show(harness: &Harness) -> String {
             ("DESCRIPTION", harness.description.clone()),
             ("BINARY", harness.binary.clone()),
             ("SETUP", harness.setup_hint()),
+            ("INSTALL", super::show::install(harness).unwrap_or_default()),
         ],
     );
     let rows = runtime::planned_steps(harness)
@@ -52,8 +53,9 @@ pub fn plan(harness: &Harness, capability: Capability) -> String {
     let plan = harness
         .plan(capability)
         .expect("validated harness capability");
+    let requires = plan.command.requires.join(", ");
     if style::plain() {
-        return format!(
+        let mut out = format!(
             "{}:{}\n{}\ncommand: {}\nenv: {}\n",
             harness.name,
             capability,
@@ -61,25 +63,34 @@ pub fn plan(harness: &Harness, capability: Capability) -> String {
             plan.command.render(),
             harness.setup_hint()
         );
+        if !requires.is_empty() {
+            out.push_str(&format!("requires: {requires}\n"));
+        }
+        return out;
     }
-    table::fields(
-        &format!("Plan: {} {}", harness.name, capability),
-        &[
-            ("SUMMARY", plan.summary.clone()),
-            ("COMMAND", plan.command.render()),
-            ("ENVIRONMENT", harness.setup_hint()),
-        ],
-    )
+    let mut fields = vec![
+        ("SUMMARY", plan.summary.clone()),
+        ("COMMAND", plan.command.render()),
+        ("ENVIRONMENT", harness.setup_hint()),
+    ];
+    if !requires.is_empty() {
+        fields.push(("REQUIRES", requires));
+    }
+    table::fields(&format!("Plan: {} {}", harness.name, capability), &fields)
 }
 
 fn plain_show(harness: &Harness) -> String {
     let mut out = format!(
-        "{} ({})\n{}\nsetup: {}\nagent loop:\n",
+        "{} ({})\n{}\nsetup: {}\n",
         harness.display,
         harness.name,
         harness.description,
         harness.setup_hint()
     );
+    if let Some(command) = super::show::install(harness) {
+        out.push_str(&format!("install: {command}\n"));
+    }
+    out.push_str("agent loop:\n");
     for plan in runtime::planned_steps(harness) {
         out.push_str(&format!("  {}: {}\n", plan.capability, plan.summary));
     }
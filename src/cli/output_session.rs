@@ -0,0 +1,35 @@
+use super::{style, table};
+use crate::context::{self, Session};
+use std::path::Path;
+
+const RECENT: usize = 3;
+
+pub fn current(session: Option<Session>, home: &Path) -> String {
+    let active = session
+        .map(|session| session.active_harness)
+        .unwrap_or_else(|| "none".to_string());
+    let recent = context::recent(&context::history(home), RECENT).join(", ");
+    if style::plain() {
+        let mut out = format!("active harness = {active}\n");
+        if !recent.is_empty() {
+            out.push_str(&format!("recent = {recent}\n"));
+        }
+        return out;
+    }
+    let mut fields = vec![("HARNESS", active)];
+    if !recent.is_empty() {
+        fields.push(("RECENT", recent));
+    }
+    table::fields("Active Harness", &fields)
+}
+
+pub fn selected(name: &str) -> String {
+    if style::plain() {
+        return format!("active harness = {name}\n");
+    }
+    format!(
+        "{}\n{}",
+        style::success("Active harness updated"),
+        table::fields("Active Harness", &[("HARNESS", name.to_string())])
+    )
+}
@@ -0,0 +1,84 @@
+use super::catalog;
+use crate::cli::args::ShowFormat;
+use crate::contracts::{Capability, Harness};
+use crate::runtime;
+
+pub fn show(harness: &Harness, format: ShowFormat) -> String {
+    match format {
+        ShowFormat::Text => catalog::show(harness),
+        ShowFormat::Json => json(harness),
+        ShowFormat::Markdown => markdown(harness),
+    }
+}
+
+pub(super) fn install(harness: &Harness) -> Option<String> {
+    harness
+        .plan(Capability::Download)
+        .map(|plan| plan.command.render())
+}
+
+fn json(harness: &Harness) -> String {
+    let install = install(harness).map_or_else(|| "null".to_string(), |command| quote(&command));
+    let list = |items: Vec<String>| {
+        let quoted = items.iter().map(|item| quote(item)).collect::<Vec<_>>();
+        format!("[{}]", quoted.join(","))
+    };
+    let requires = harness
+        .plan(Capability::Download)
+        .map(|plan| plan.command.requires.clone())
+        .unwrap_or_default();
+    let capabilities = runtime::planned_steps(harness)
+        .into_iter()
+        .map(|plan| plan.capability.to_string())
+        .collect();
+    format!(
+        "{{\"name\":{},\"display\":{},\"description\":{},\"binary\":{},\"env\":{},\"setup\":{},\"install\":{install},\"install_requires\":{},\"capabilities\":{}}}\n",
+        quote(&harness.name),
+        quote(&harness.display),
+        quote(&harness.description),
+        quote(&harness.binary),
+        list(harness.env.clone()),
+        quote(&harness.setup_hint()),
+        list(requires),
+        list(capabilities)
+    )
+}
+
+fn markdown(harness: &Harness) -> String {
+    let mut out = format!(
+        "## {} (`{}`)\n\n{}\n\n- Binary: `{}`\n- Setup: {}\n",
+        harness.display,
+        harness.name,
+        harness.description,
+        harness.binary,
+        harness.setup_hint()
+    );
+    if let Some(command) = install(harness) {
+        out.push_str(&format!("- Install: `{command}`\n"));
+    }
+    out.push_str("\n| Capability | Behavior |\n| --- | --- |\n");
+    for plan in runtime::planned_steps(harness) {
+        out.push_str(&format!(
+            "| {} | {} |\n",
+            plan.capability,
+            plan.summary.replace('|', "\\|")
+        ));
+    }
+    out
+}
+
+pub fn quote(value: &str) -> String {
+    let mut out = String::from("\"");
+    for char in value.chars() {
+        match char {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            char if char.is_control() => out.push_str(&format!("\\u{:04x}", char as u32)),
+            char => out.push(char),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -29,6 +29,8 @@ fn mock_harness(binary: &str, env_mode: EnvMode, env: Vec<String>) -> Harness {
         binary: binary.into(),
         env_mode,
         env,
+        full_screen: false,
+        project_args: vec![],
         capabilities: vec![],
     }
 }
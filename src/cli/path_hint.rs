@@ -0,0 +1,82 @@
+use crate::contracts::{Capability, Harness};
+use crate::security;
+use std::process::Command;
+
+pub fn after_install(harness: &Harness, (code, mut body): (i32, String)) -> (i32, String) {
+    if code != 0 || security::command_on_path(&harness.binary) {
+        return (code, body);
+    }
+    let installer = harness
+        .plan(Capability::Download)
+        .map(|plan| plan.command.command.clone())
+        .unwrap_or_default();
+    let shell = std::env::var("SHELL").unwrap_or_default();
+    body.push_str(&hint(
+        &harness.binary,
+        bin_dir(&installer).as_deref(),
+        &shell,
+    ));
+    (code, body)
+}
+
+pub fn hint(binary: &str, bin_dir: Option<&str>, shell: &str) -> String {
+    let Some(dir) = bin_dir else {
+        return format!("\n  hint: installed, but `{binary}` is not on PATH yet; open a new shell or add the installer's bin directory to PATH.\n");
+    };
+    let (rc, line) = match shell.rsplit('/').next().unwrap_or_default() {
+        "fish" => ("~/.config/fish/config.fish", format!("fish_add_path {dir}")),
+        "zsh" => ("~/.zshrc", format!("export PATH=\"{dir}:$PATH\"")),
+        "bash" => ("~/.bashrc", format!("export PATH=\"{dir}:$PATH\"")),
+        _ => ("~/.profile", format!("export PATH=\"{dir}:$PATH\"")),
+    };
+    format!("\n  hint: installed, but `{binary}` is not on PATH; {dir} is missing from PATH. Add this line to {rc}:\n    {line}\n")
+}
+
+fn bin_dir(installer: &str) -> Option<String> {
+    let (program, args): (&str, &[&str]) = match installer {
+        "npm" => ("npm", &["prefix", "-g"]),
+        "pnpm" => ("pnpm", &["bin", "-g"]),
+        "bun" => ("bun", &["pm", "bin", "-g"]),
+        "uv" => ("uv", &["tool", "dir", "--bin"]),
+        _ => return None,
+    };
+    let output = Command::new(program).args(args).output().ok()?;
+    let dir = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if !output.status.success() || dir.is_empty() {
+        return None;
+    }
+    match installer {
+        "npm" if !cfg!(windows) => Some(format!("{dir}/bin")),
+        _ => Some(dir),
+    }
+}
+
+pub fn command_error(harness: &str, binary: &str, error: std::io::Error) -> String {
+    if error.kind() == std::io::ErrorKind::NotFound {
+        return format!("{harness} binary '{binary}' was not found on PATH; run `terminal-jarvis install {harness}` or `terminal-jarvis plan {harness} download`");
+    }
+    error.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hint;
+
+    #[test]
+    fn hint_names_the_rc_file_for_the_detected_shell() {
+        let zsh = hint("codex", Some("/home/me/.npm/bin"), "/usr/bin/zsh");
+        assert!(zsh.contains("Add this line to ~/.zshrc"));
+        assert!(zsh.contains("export PATH=\"/home/me/.npm/bin:$PATH\""));
+        let fish = hint("aider", Some("/home/me/.local/bin"), "/usr/bin/fish");
+        assert!(fish.contains("~/.config/fish/config.fish"));
+        assert!(fish.contains("fish_add_path /home/me/.local/bin"));
+        assert!(hint("codex", Some("/opt/bin"), "").contains("~/.profile"));
+    }
+
+    #[test]
+    fn hint_without_a_bin_dir_still_explains_the_problem() {
+        let text = hint("q", None, "/bin/bash");
+        assert!(text.contains("`q` is not on PATH"));
+        assert!(!text.contains("Add this line"));
+    }
+}
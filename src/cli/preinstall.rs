@@ -0,0 +1,97 @@
+use super::{style, verbosity};
+use crate::contracts::{Capability, CommandPlan, Harness};
+use crate::runtime;
+use crate::security::{self, Verdict, Vulnerability};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const ALLOW: &str = "TERMINAL_JARVIS_ALLOW_VULNERABLE";
+
+pub fn check(harness: &Harness, home: &Path) -> Result<(), String> {
+    let npm = harness
+        .plan(Capability::Download)
+        .is_some_and(|plan| ["npm", "pnpm", "bun"].contains(&plan.command.command.as_str()));
+    let Some(runtime::Source::Npm(package)) = runtime::source(harness).filter(|_| npm) else {
+        return Ok(());
+    };
+    let Some(found) = audit(&package, home) else {
+        let notice = format!(
+            "could not run npm audit for {package}; installing without a vulnerability check"
+        );
+        verbosity::notice(&style::warning(&notice));
+        return Ok(());
+    };
+    match security::verdict(found) {
+        Verdict::Pass => Ok(()),
+        Verdict::Warn(found) => {
+            verbosity::notice(&style::warning(&report(
+                &package,
+                "moderate advisories",
+                &found,
+            )));
+            Ok(())
+        }
+        Verdict::Block(found) if std::env::var(ALLOW).is_ok_and(|value| value == "1") => {
+            let summary = "high or critical advisories";
+            verbosity::notice(&style::warning(&report(&package, summary, &found)));
+            Ok(())
+        }
+        Verdict::Block(found) => Err(format!(
+            "{}\nrefusing to install; review the advisories or set {ALLOW}=1 to install anyway",
+            report(&package, "high or critical advisories", &found)
+        )),
+    }
+}
+
+fn report(package: &str, summary: &str, found: &[Vulnerability]) -> String {
+    let mut out = format!("npm audit found {summary} for {package}:");
+    for vulnerability in found {
+        out.push_str(&format!(
+            "\n  {} {}: {}",
+            vulnerability.severity, vulnerability.module_name, vulnerability.advisory_url
+        ));
+    }
+    out
+}
+
+fn audit(package: &str, home: &Path) -> Option<Vec<Vulnerability>> {
+    let name = package.replace(['@', '/'], "_");
+    let dir = home
+        .join("audit")
+        .join(format!("{}-{name}", std::process::id()));
+    fs::create_dir_all(dir.parent()?).ok()?;
+    fs::create_dir(&dir).ok()?;
+    let manifest = format!(
+        "{{\"name\":\"terminal-jarvis-audit\",\"version\":\"0.0.0\",\"private\":true,\"dependencies\":{{\"{package}\":\"latest\"}}}}\n"
+    );
+    let prefix = dir.display().to_string();
+    let npm = |args: &[&str]| {
+        let mut args = args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        args.extend(["--prefix".to_string(), prefix.clone()]);
+        runtime::output(&CommandPlan::new("npm".into(), args), TIMEOUT)
+    };
+    let report = fs::write(dir.join("package.json"), manifest)
+        .ok()
+        .and_then(|_| {
+            npm(&[
+                "install",
+                "--package-lock-only",
+                "--ignore-scripts",
+                "--no-audit",
+                "--no-fund",
+            ])
+        })
+        .filter(|(locked, _)| *locked)
+        .and_then(|_| npm(&["audit", "--json"]));
+    let _ = fs::remove_dir_all(&dir);
+    let (_, report) = report?;
+    report
+        .contains("\"auditReportVersion\"")
+        .then(|| security::vulnerabilities(&report))
+}
+
+#[cfg(test)]
+#[path = "preinstall_test.rs"]
+mod tests;
@@ -0,0 +1,55 @@
+use super::*;
+use crate::catalog;
+
+const HIGH: &str = r#"{"auditReportVersion":2,"vulnerabilities":{"tar":{"via":[{"source":1,"name":"tar","url":"https://github.com/advisories/GHSA-high","severity":"high"}]}}}"#;
+
+#[cfg(unix)]
+#[test]
+fn high_severity_report_from_npm_refuses_the_install() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let _guard = crate::ENV_LOCK
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    let root = std::env::temp_dir().join(format!("tj-preinstall-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    let (bin, home) = (root.join("bin"), root.join("home"));
+    fs::create_dir_all(&bin).unwrap();
+    fs::write(root.join("report.json"), HIGH).unwrap();
+    let npm = bin.join("npm");
+    let script = format!(
+        "#!/bin/sh\ncase \"$1\" in audit) cat {};; esac\n",
+        root.join("report.json").display()
+    );
+    fs::write(&npm, script).unwrap();
+    fs::set_permissions(&npm, fs::Permissions::from_mode(0o755)).unwrap();
+    let codex = catalog::load(Path::new("harnesses"))
+        .unwrap()
+        .into_iter()
+        .find(|harness| harness.name == "codex")
+        .unwrap();
+    let old_path = std::env::var("PATH").unwrap_or_default();
+    std::env::set_var("PATH", format!("{}:{old_path}", bin.display()));
+    std::env::remove_var(ALLOW);
+    let result = check(&codex, &home);
+    std::env::set_var("PATH", old_path);
+    let error = result.unwrap_err();
+    assert!(
+        error.contains("high tar: https://github.com/advisories/GHSA-high"),
+        "{error}"
+    );
+    assert!(error.contains("refusing to install"), "{error}");
+    let scratch = fs::read_dir(home.join("audit")).unwrap().count();
+    assert_eq!(scratch, 0, "the audit directory is removed afterwards");
+}
+
+#[test]
+fn audit_refuses_to_reuse_an_existing_scratch_directory() {
+    let home = std::env::temp_dir().join(format!("tj-preinstall-reuse-{}", std::process::id()));
+    let dir = home
+        .join("audit")
+        .join(format!("{}-left_over", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    assert_eq!(audit("left/over", &home), None);
+    assert!(dir.exists(), "an existing directory is left alone");
+}
@@ -0,0 +1,98 @@
+use super::{invoke, preinstall, style, table};
+use crate::contracts::{Capability, EnvMode, Harness, LaunchOptions};
+use crate::{gates, runtime, security};
+use std::path::Path;
+
+pub const USAGE: &str =
+    "usage: terminal-jarvis install --preset minimal|full|free [--parallel N] (default 4 at a time)";
+const DEFAULT_JOBS: usize = 4;
+const MINIMAL: [&str; 2] = ["claude", "codex"];
+const PRESENT: &str = "already installed";
+const HEADERS: [&str; 3] = ["HARNESS", "RESULT", "DETAIL"];
+
+pub fn members<'a>(preset: &str, harnesses: &'a [Harness]) -> Result<Vec<&'a Harness>, String> {
+    let keep: fn(&Harness) -> bool = match preset {
+        "minimal" => |harness| MINIMAL.contains(&harness.name.as_str()),
+        "full" => |_| true,
+        "free" => |harness| harness.env_mode == EnvMode::None || harness.env.is_empty(),
+        other => {
+            return Err(format!(
+                "unknown preset '{other}'; expected minimal, full, or free"
+            ))
+        }
+    };
+    Ok(harnesses.iter().filter(|harness| keep(harness)).collect())
+}
+
+pub fn install(
+    words: &[String],
+    harnesses: &[Harness],
+    home: &Path,
+) -> Result<(i32, String), String> {
+    let (preset, jobs) = parse(words)?;
+    let members = members(preset, harnesses)?;
+    gates::preflight(home)?;
+    let install = |harness: &&Harness| install_one(harnesses, harness, home);
+    let results = runtime::run_bounded(&members, jobs, install);
+    let mut rows = Vec::new();
+    for (row, error) in results {
+        error.inspect(|error| eprintln!("{}", style::error(error)));
+        rows.push(row);
+    }
+    let count = |status: &str| rows.iter().filter(|row| row[1] == status).count();
+    let (installed, present) = (count("installed"), count(PRESENT));
+    let code = i32::from(installed + present < rows.len());
+    let mut total = format!("{installed}/{} installed", rows.len() - present);
+    if present > 0 {
+        total.push_str(&format!(", {present} already installed"));
+    }
+    if style::plain() {
+        let mut out = rows
+            .iter()
+            .map(|row| match row[2].as_str() {
+                "" => format!("{}: {}\n", row[0], row[1]),
+                detail => format!("{}: {} - {detail}\n", row[0], row[1]),
+            })
+            .collect::<String>();
+        out.push_str(&format!("preset {preset}: {total}\n"));
+        return Ok((code, out));
+    }
+    let title = format!("Preset: {preset} ({total})");
+    Ok((code, table::render(&title, &HEADERS, &rows)))
+}
+
+fn parse(words: &[String]) -> Result<(&str, usize), String> {
+    match words {
+        [preset] => Ok((preset, DEFAULT_JOBS)),
+        [preset, flag, jobs] | [flag, jobs, preset] if flag == "--parallel" => {
+            let count = jobs.parse().ok().filter(|count| *count > 0);
+            let count = count
+                .ok_or_else(|| format!("--parallel expects a positive number, got '{jobs}'"))?;
+            Ok((preset, count))
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn install_one(all: &[Harness], harness: &Harness, home: &Path) -> (Vec<String>, Option<String>) {
+    let name = harness.name.clone();
+    if security::command_on_path(&harness.binary) {
+        return (vec![name, PRESENT.to_string(), String::new()], None);
+    }
+    if let Err(error) = preinstall::check(harness, home) {
+        let detail = error.lines().next().unwrap_or_default().to_string();
+        return (vec![name, "blocked".to_string(), detail], Some(error));
+    }
+    let options = LaunchOptions::default();
+    match invoke::capability(all, &name, Capability::Download, &[], &options) {
+        Ok((0, _)) => (vec![name, "installed".to_string(), String::new()], None),
+        Ok((_, output)) | Err(output) => {
+            let detail = output.lines().next().unwrap_or_default().to_string();
+            (vec![name, "failed".to_string(), detail], Some(output))
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "preset_test.rs"]
+mod tests;
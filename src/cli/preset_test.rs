@@ -0,0 +1,13 @@
+use super::*;
+
+fn words(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+#[test]
+fn presets_install_four_at_a_time_unless_told_otherwise() {
+    assert_eq!(parse(&words(&["full"])), Ok(("full", DEFAULT_JOBS)));
+    assert_eq!(DEFAULT_JOBS, 4);
+    assert_eq!(parse(&words(&["full", "--parallel", "1"])), Ok(("full", 1)));
+    assert!(USAGE.contains("default 4"));
+}
@@ -0,0 +1,91 @@
+use super::{style, table};
+use crate::contracts::{Harness, LaunchOptions};
+use crate::security;
+
+pub fn render(
+    harnesses: &[Harness],
+    name: &str,
+    options: &LaunchOptions,
+) -> Result<(i32, String), String> {
+    let harness = harnesses
+        .iter()
+        .find(|harness| harness.name == name)
+        .ok_or_else(|| format!("unknown harness '{name}'"))?;
+    let rows = rows(harness, options, |name| std::env::var(name).ok());
+    if style::plain() {
+        if rows.is_empty() {
+            return Ok((0, format!("{name}: no catalog or --tool-env variables\n")));
+        }
+        let lines = rows
+            .iter()
+            .map(|row| format!("{} = {} ({})\n", row[0], row[1], row[2]))
+            .collect();
+        return Ok((0, lines));
+    }
+    let title = format!("Environment: {name}");
+    Ok((
+        0,
+        table::render(&title, &["VARIABLE", "VALUE", "SOURCE"], &rows),
+    ))
+}
+
+fn rows<F>(harness: &Harness, options: &LaunchOptions, lookup: F) -> Vec<Vec<String>>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut rows = Vec::new();
+    for name in harness.env.iter().filter(|name| !options.provides(name)) {
+        let (value, source) = match lookup(name) {
+            Some(value) => (security::redact(&value), "environment"),
+            None => ("-".to_string(), "unset"),
+        };
+        rows.push(vec![name.clone(), value, source.to_string()]);
+    }
+    for (name, value) in &options.env {
+        let shown = match harness.env.contains(name) || security::looks_secret(name) {
+            true => security::redact(value),
+            false => value.clone(),
+        };
+        rows.push(vec![name.clone(), shown, "--tool-env".to_string()]);
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::EnvMode;
+
+    #[test]
+    fn catalog_keys_and_secret_overrides_are_redacted() {
+        let harness = Harness {
+            name: "opencode".into(),
+            display: "OpenCode".into(),
+            description: String::new(),
+            binary: "opencode".into(),
+            env_mode: EnvMode::Any,
+            env: vec!["OPENCODE_API_KEY".into(), "OPENAI_API_KEY".into()],
+            full_screen: false,
+            project_args: vec![],
+            capabilities: vec![],
+        };
+        let options = LaunchOptions {
+            env: vec![
+                ("OPENAI_API_KEY".into(), "sk-one".into()),
+                ("RUST_LOG".into(), "debug".into()),
+                ("EXTRA_TOKEN".into(), "abc".into()),
+            ],
+            ..LaunchOptions::default()
+        };
+        let rows = rows(&harness, &options, |name| {
+            (name == "OPENCODE_API_KEY").then(|| "oc-secret".to_string())
+        });
+        let expected = [
+            ["OPENCODE_API_KEY", "<redacted, 9 chars>", "environment"],
+            ["OPENAI_API_KEY", "<redacted, 6 chars>", "--tool-env"],
+            ["RUST_LOG", "debug", "--tool-env"],
+            ["EXTRA_TOKEN", "<redacted, 3 chars>", "--tool-env"],
+        ];
+        assert_eq!(rows, expected.map(|row| row.map(String::from).to_vec()));
+    }
+}
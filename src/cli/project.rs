@@ -0,0 +1,53 @@
+use super::resolve::Invocation;
+use crate::context::{self, ProjectConfig};
+use crate::contracts::{Capability, Harness, LaunchOptions};
+use crate::security;
+use std::env;
+
+pub fn load(harnesses: &[Harness]) -> Result<Option<ProjectConfig>, String> {
+    let cwd = env::current_dir().map_err(|error| error.to_string())?;
+    let Some(config) = context::project(&cwd)? else {
+        return Ok(None);
+    };
+    check(&config, harnesses).map_err(|error| format!("{}: {error}", config.path.display()))?;
+    Ok(Some(config))
+}
+
+pub fn check(config: &ProjectConfig, harnesses: &[Harness]) -> Result<(), String> {
+    let find = |name: &str| {
+        harnesses
+            .iter()
+            .find(|harness| harness.name == name)
+            .ok_or_else(|| format!("unknown harness '{name}'"))
+    };
+    if let Some(preferred) = &config.preferred {
+        find(preferred)?;
+    }
+    for (name, defaults) in &config.tools {
+        let harness = find(name)?;
+        security::project_args(harness, &defaults.args)?;
+        for (variable, _) in &defaults.env {
+            security::project_env(harness, variable)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn apply(
+    config: Option<&ProjectConfig>,
+    invocation: &mut Invocation,
+    options: &mut LaunchOptions,
+) {
+    let Some(defaults) = config.and_then(|config| config.tools.get(&invocation.harness)) else {
+        return;
+    };
+    let launch = [Capability::Ui, Capability::Headless, Capability::Yolo];
+    if launch.contains(&invocation.capability) {
+        invocation.extra.splice(0..0, defaults.args.iter().cloned());
+    }
+    options.env.splice(0..0, defaults.env.iter().cloned());
+}
+
+#[cfg(test)]
+#[path = "project_test.rs"]
+mod tests;
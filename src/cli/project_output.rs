@@ -0,0 +1,53 @@
+use super::{project, style, table};
+use crate::contracts::Harness;
+use crate::{context, security};
+use std::path::Path;
+
+pub fn effective(harnesses: &[Harness], home: &Path) -> Result<String, String> {
+    let config = project::load(harnesses)?;
+    let file = config
+        .as_ref()
+        .map(|config| config.path.display().to_string());
+    let session = context::load(home).map_err(|error| error.to_string())?;
+    let home_source = match std::env::var_os("TERMINAL_JARVIS_HOME") {
+        Some(value) if !value.is_empty() => "TERMINAL_JARVIS_HOME",
+        _ => "default",
+    };
+    let mut rows = vec![vec![
+        "home".to_string(),
+        home.display().to_string(),
+        home_source.to_string(),
+    ]];
+    let active = match (
+        config.as_ref().and_then(|config| config.preferred.clone()),
+        session,
+    ) {
+        (Some(name), _) => (name, file.clone().unwrap_or_default()),
+        (None, Some(session)) => (session.active_harness, "session".to_string()),
+        (None, None) => ("none".to_string(), "default".to_string()),
+    };
+    rows.push(vec!["active harness".to_string(), active.0, active.1]);
+    for (name, defaults) in config.iter().flat_map(|config| &config.tools) {
+        let env = defaults
+            .env
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>();
+        for (setting, values) in [("args", &defaults.args), ("env", &env)] {
+            let value = security::redact_args(values).join(" ");
+            rows.push(vec![
+                format!("{name}.{setting}"),
+                value,
+                file.clone().unwrap_or_default(),
+            ]);
+        }
+    }
+    if style::plain() {
+        let lines = rows
+            .iter()
+            .map(|row| format!("{} = {} ({})\n", row[0], row[1], row[2]));
+        return Ok(lines.collect());
+    }
+    let headers = ["SETTING", "VALUE", "SOURCE"];
+    Ok(table::render("Effective Configuration", &headers, &rows))
+}
@@ -0,0 +1,97 @@
+use super::*;
+use crate::context::ToolDefaults;
+use crate::contracts::{CapabilityPlan, CommandPlan, EnvMode};
+
+fn codex() -> Harness {
+    let plan = |capability, args: &[&str]| CapabilityPlan {
+        capability,
+        summary: String::new(),
+        command: CommandPlan::new(
+            "codex".into(),
+            args.iter().map(|arg| arg.to_string()).collect(),
+        ),
+    };
+    Harness {
+        name: "codex".into(),
+        display: "Codex".into(),
+        description: String::new(),
+        binary: "codex".into(),
+        env_mode: EnvMode::None,
+        env: vec![],
+        full_screen: false,
+        project_args: vec![],
+        capabilities: vec![
+            plan(Capability::Ui, &[]),
+            plan(
+                Capability::Yolo,
+                &["--dangerously-bypass-approvals-and-sandbox"],
+            ),
+        ],
+    }
+}
+
+fn config(args: &[&str], env: &[(&str, &str)]) -> ProjectConfig {
+    let defaults = ToolDefaults {
+        args: args.iter().map(|arg| arg.to_string()).collect(),
+        env: env
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect(),
+    };
+    ProjectConfig {
+        tools: [("codex".to_string(), defaults)].into(),
+        ..ProjectConfig::default()
+    }
+}
+
+fn invocation(capability: Capability, extra: &[&str]) -> Invocation {
+    Invocation {
+        harness: "codex".into(),
+        capability,
+        extra: extra.iter().map(|arg| arg.to_string()).collect(),
+    }
+}
+
+#[test]
+fn project_values_come_before_command_line_values() {
+    let project = config(&["--model", "o3"], &[("MODE", "project")]);
+    let mut launch = invocation(Capability::Headless, &["fix", "it"]);
+    let mut options = LaunchOptions {
+        env: vec![("MODE".into(), "flag".into())],
+        ..LaunchOptions::default()
+    };
+    apply(Some(&project), &mut launch, &mut options);
+    assert_eq!(launch.extra, ["--model", "o3", "fix", "it"]);
+    assert_eq!(options.env.last().unwrap().1, "flag");
+    assert_eq!(options.env[0].1, "project");
+}
+
+#[test]
+fn default_args_only_reach_launch_capabilities() {
+    let project = config(&["--model", "o3"], &[]);
+    let mut launch = invocation(Capability::Version, &[]);
+    apply(Some(&project), &mut launch, &mut LaunchOptions::default());
+    assert!(launch.extra.is_empty());
+}
+
+#[test]
+fn unknown_harnesses_bypasses_and_loaders_are_refused() {
+    let harnesses = [codex()];
+    assert_eq!(check(&config(&["--model", "o3"], &[]), &harnesses), Ok(()));
+    let bypass = config(&["--dangerously-bypass-approvals-and-sandbox"], &[]);
+    assert!(check(&bypass, &harnesses)
+        .unwrap_err()
+        .contains("cannot include --dangerously"));
+    let loader = config(&[], &[("NODE_OPTIONS", "--require ./hook.js")]);
+    assert!(check(&loader, &harnesses)
+        .unwrap_err()
+        .contains("cannot set NODE_OPTIONS"));
+    let ghost = ProjectConfig {
+        preferred: Some("ghost".into()),
+        ..ProjectConfig::default()
+    };
+    assert_eq!(
+        check(&ghost, &harnesses),
+        Err("unknown harness 'ghost'".into())
+    );
+}
@@ -0,0 +1,85 @@
+use crate::context::Launch;
+use crate::contracts::Harness;
+
+pub fn recency<F>(
+    harnesses: &[Harness],
+    launches: &[Launch],
+    descending: bool,
+    installed: F,
+) -> Vec<Harness>
+where
+    F: Fn(&Harness) -> bool,
+{
+    let last = |name: &str| {
+        launches
+            .iter()
+            .rev()
+            .position(|launch| launch.harness == name)
+    };
+    let mut sorted = harnesses.to_vec();
+    sorted.sort_by_cached_key(|harness| match (installed(harness), last(&harness.name)) {
+        (true, Some(age)) => (0, age),
+        (true, None) => (1, 0),
+        (false, _) => (2, 0),
+    });
+    if descending {
+        sorted.reverse();
+    }
+    sorted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::EnvMode;
+
+    fn harness(name: &str) -> Harness {
+        Harness {
+            name: name.to_string(),
+            display: name.to_string(),
+            description: String::new(),
+            binary: name.to_string(),
+            env_mode: EnvMode::None,
+            env: vec![],
+            full_screen: false,
+            project_args: vec![],
+            capabilities: vec![],
+        }
+    }
+
+    fn names(harnesses: &[Harness]) -> Vec<&str> {
+        harnesses
+            .iter()
+            .map(|harness| harness.name.as_str())
+            .collect()
+    }
+
+    fn launch(at: u64, harness: &str) -> Launch {
+        Launch {
+            at,
+            harness: harness.to_string(),
+            capability: "ui".to_string(),
+            ..Launch::default()
+        }
+    }
+
+    #[test]
+    fn recent_sort_puts_last_used_first_then_unused_then_uninstalled() {
+        let hs = [
+            harness("pi"),
+            harness("qwen"),
+            harness("aider"),
+            harness("codex"),
+        ];
+        let launches = [
+            launch(1, "codex"),
+            launch(2, "gone"),
+            launch(3, "qwen"),
+            launch(4, "pi"),
+        ];
+        let installed = |harness: &Harness| harness.name != "pi";
+        let sorted = recency(&hs, &launches, false, installed);
+        assert_eq!(names(&sorted), ["qwen", "codex", "aider", "pi"]);
+        assert_eq!(names(&recency(&hs, &[], false, |_| true)), names(&hs));
+    }
+}
@@ -1,3 +1,4 @@
+use super::verbosity;
 use crate::context;
 use crate::contracts::{Capability, Harness};
 use std::path::Path;
@@ -8,32 +9,31 @@ pub struct Invocation {
     pub extra: Vec<String>,
 }
 
-pub fn run(words: &[String], harnesses: &[Harness], home: &Path) -> Result<Invocation, String> {
+pub fn run(
+    words: &[String],
+    harnesses: &[Harness],
+    home: &Path,
+    preferred: Option<&str>,
+) -> Result<Invocation, String> {
+    let current = || preferred.map_or_else(|| active(home, harnesses), |name| Ok(name.into()));
     if words.is_empty() {
-        return Ok(invocation(active(home)?, Capability::Ui, Vec::new()));
+        return Ok(invocation(current()?, Capability::Ui, Vec::new()));
     }
     let first = &words[0];
     if has_harness(harnesses, first) {
         return Ok(for_harness(first, &words[1..]));
     }
     if first == "headless" {
-        return Ok(invocation(
-            active(home)?,
-            Capability::Headless,
-            words[1..].to_vec(),
-        ));
+        let prompt = words[1..].to_vec();
+        return Ok(invocation(current()?, Capability::Headless, prompt));
     }
     if let Some(capability) = Capability::parse(first) {
         if words.len() == 1 {
-            return Ok(invocation(active(home)?, capability, Vec::new()));
+            return Ok(invocation(current()?, capability, Vec::new()));
         }
-        return Ok(invocation(
-            active(home)?,
-            Capability::Headless,
-            words.to_vec(),
-        ));
+        return Ok(invocation(current()?, Capability::Headless, words.to_vec()));
     }
-    let selected = active(home)?;
+    let selected = current()?;
     if has_harness(harnesses, &selected) {
         return Ok(invocation(selected, Capability::Headless, words.to_vec()));
     }
@@ -50,11 +50,8 @@ pub fn direct(
             "unknown command or harness '{harness}'; run `terminal-jarvis list`"
         ));
     }
-    Ok(invocation(
-        harness.to_string(),
-        Capability::Ui,
-        extra.to_vec(),
-    ))
+    let extra = extra.to_vec();
+    Ok(invocation(harness.to_string(), Capability::Ui, extra))
 }
 
 fn for_harness(harness: &str, rest: &[String]) -> Invocation {
@@ -68,13 +65,20 @@ fn for_harness(harness: &str, rest: &[String]) -> Invocation {
     }
 }
 
-fn active(home: &Path) -> Result<String, String> {
-    context::load(home)
-        .map_err(|error| error.to_string())?
-        .map(|session| session.active_harness)
-        .ok_or_else(|| {
+fn active(home: &Path, harnesses: &[Harness]) -> Result<String, String> {
+    if let Some(session) = context::load(home).map_err(|error| error.to_string())? {
+        return Ok(session.active_harness);
+    }
+    let last =
+        context::last_launch(home, |name| has_harness(harnesses, name)).ok_or_else(|| {
             "no active harness; run `terminal-jarvis use <harness>` or pass a harness".to_string()
-        })
+        })?;
+    verbosity::notice(&format!(
+        "continuing with {} (last used {})",
+        last.harness,
+        last.age()
+    ));
+    Ok(last.harness)
 }
 
 fn has_harness(harnesses: &[Harness], name: &str) -> bool {
@@ -27,6 +27,8 @@ fn harness(name: &str) -> Harness {
         binary: name.to_string(),
         env_mode: EnvMode::None,
         env: vec![],
+        full_screen: false,
+        project_args: vec![],
         capabilities: vec![],
     }
 }
@@ -39,7 +41,13 @@ fn s(value: &str) -> String {
 fn prompt_starting_with_capability_runs_headless_not_capability() {
     let home = tmp_home("opencode");
     let harnesses = vec![harness("opencode")];
-    let inv = run(&[s("update"), s("my"), s("database")], &harnesses, &home).unwrap();
+    let inv = run(
+        &[s("update"), s("my"), s("database")],
+        &harnesses,
+        &home,
+        None,
+    )
+    .unwrap();
     assert_eq!(inv.harness, "opencode");
     assert_eq!(inv.capability, Capability::Headless);
     assert_eq!(inv.extra, vec![s("update"), s("my"), s("database")]);
@@ -49,7 +57,7 @@ fn prompt_starting_with_capability_runs_headless_not_capability() {
 fn dangerous_capability_prompt_is_not_executed() {
     let home = tmp_home("opencode");
     let harnesses = vec![harness("opencode")];
-    let inv = run(&[s("yolo"), s("clean"), s("tmp")], &harnesses, &home).unwrap();
+    let inv = run(&[s("yolo"), s("clean"), s("tmp")], &harnesses, &home, None).unwrap();
     assert_eq!(inv.capability, Capability::Headless);
     assert_eq!(inv.extra, vec![s("yolo"), s("clean"), s("tmp")]);
 }
@@ -58,7 +66,7 @@ fn dangerous_capability_prompt_is_not_executed() {
 fn single_capability_word_still_runs_that_capability() {
     let home = tmp_home("opencode");
     let harnesses = vec![harness("opencode")];
-    let inv = run(&[s("version")], &harnesses, &home).unwrap();
+    let inv = run(&[s("version")], &harnesses, &home, None).unwrap();
     assert_eq!(inv.capability, Capability::Version);
     assert!(inv.extra.is_empty());
 }
@@ -67,7 +75,7 @@ fn single_capability_word_still_runs_that_capability() {
 fn headless_keyword_form_runs_headless_capability() {
     let home = tmp_home("opencode");
     let harnesses = vec![harness("opencode")];
-    let inv = run(&[s("headless"), s("summarize")], &harnesses, &home).unwrap();
+    let inv = run(&[s("headless"), s("summarize")], &harnesses, &home, None).unwrap();
     assert_eq!(inv.capability, Capability::Headless);
     assert_eq!(inv.extra, vec![s("summarize")]);
 }
@@ -76,7 +84,16 @@ fn headless_keyword_form_runs_headless_capability() {
 fn explicit_harness_capability_is_preserved() {
     let home = tmp_home("opencode");
     let harnesses = vec![harness("opencode")];
-    let inv = run(&[s("opencode"), s("version")], &harnesses, &home).unwrap();
+    let inv = run(&[s("opencode"), s("version")], &harnesses, &home, None).unwrap();
     assert_eq!(inv.harness, "opencode");
     assert_eq!(inv.capability, Capability::Version);
 }
+
+#[test]
+fn project_preferred_harness_beats_the_session() {
+    let home = tmp_home("opencode");
+    let harnesses = vec![harness("opencode"), harness("codex")];
+    let resolved = |preferred| run(&[], &harnesses, &home, preferred).unwrap().harness;
+    assert_eq!(resolved(Some("codex")), "codex");
+    assert_eq!(resolved(None), "opencode");
+}
@@ -0,0 +1,17 @@
+use super::{style, verbosity};
+use crate::contracts::{Capability, CommandPlan};
+use crate::{context, security};
+use std::sync::Once;
+
+static WARNED: Once = Once::new();
+
+pub fn check(capability: Capability, command: &CommandPlan) -> Result<(), String> {
+    let installs = matches!(capability, Capability::Download | Capability::Update);
+    if !installs || !context::environment().is_root || security::root_allowed() {
+        return Ok(());
+    }
+    WARNED.call_once(|| {
+        verbosity::notice(&style::warning("warning: terminal-jarvis is running as root; tool installs will create root-owned files that later non-root updates cannot change"));
+    });
+    security::root_refusal(command, false).map_or(Ok(()), Err)
+}
@@ -0,0 +1,53 @@
+use crate::contracts::{CapabilityPlan, Harness, LaunchOptions};
+use crate::runtime::{self, Sandbox};
+use crate::security;
+use std::io::IsTerminal;
+
+pub fn plan(
+    harness: &Harness,
+    plan: &CapabilityPlan,
+    extra: &[String],
+    options: &LaunchOptions,
+) -> Result<CapabilityPlan, String> {
+    let runtime = runtime::detect_runtime(security::command_on_path).ok_or_else(|| {
+        "--sandbox needs docker or podman on PATH; install one or launch without --sandbox"
+            .to_string()
+    })?;
+    let workdir = match &options.cwd {
+        Some(dir) => dir.clone(),
+        None => std::env::current_dir()
+            .map_err(|error| format!("cannot read the working directory: {error}"))?,
+    };
+    let sandbox = Sandbox {
+        runtime: runtime.to_string(),
+        image: image(),
+        workdir: workdir.display().to_string(),
+        tty: std::io::stdin().is_terminal(),
+    };
+    let mut env = harness.env.clone();
+    for (key, _) in &options.env {
+        if !env.contains(key) {
+            env.push(key.clone());
+        }
+    }
+    Ok(CapabilityPlan {
+        command: runtime::wrap(&sandbox, &plan.command, extra, &env),
+        ..plan.clone()
+    })
+}
+
+pub fn hint(binary: &str, code: i32, output: &str) -> Option<String> {
+    (code == 127 || output.contains("executable file not found")).then(|| {
+        format!(
+            "\n  hint: sandboxed launches only see tools installed in the image; `{}` does not provide `{binary}`. Set TERMINAL_JARVIS_SANDBOX_IMAGE to an image that does.\n",
+            image()
+        )
+    })
+}
+
+fn image() -> String {
+    std::env::var("TERMINAL_JARVIS_SANDBOX_IMAGE")
+        .ok()
+        .filter(|image| !image.trim().is_empty())
+        .unwrap_or_else(|| runtime::DEFAULT_IMAGE.to_string())
+}
@@ -0,0 +1,92 @@
+use super::{style, table};
+use crate::contracts::Harness;
+use crate::security;
+
+const USAGE: &str = "usage: terminal-jarvis search <query> [--installed-only]";
+
+pub fn handle(words: &[String], harnesses: &[Harness]) -> Result<String, String> {
+    let installed_only = words.iter().any(|word| word == "--installed-only");
+    let terms = words
+        .iter()
+        .filter(|word| *word != "--installed-only")
+        .collect::<Vec<_>>();
+    if terms.is_empty() || terms.iter().any(|term| term.starts_with('-')) {
+        return Err(USAGE.to_string());
+    }
+    let query = terms
+        .iter()
+        .map(|term| term.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let installed = |harness: &Harness| security::command_on_path(&harness.binary);
+    let rows = matches(&query, harnesses)
+        .into_iter()
+        .map(|harness| (harness, installed(harness)))
+        .filter(|(_, installed)| *installed || !installed_only)
+        .map(|(harness, installed)| {
+            let status = if installed {
+                "installed"
+            } else {
+                "not installed"
+            };
+            vec![
+                harness.name.clone(),
+                status.to_string(),
+                harness.description.clone(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    if rows.is_empty() {
+        return Ok(format!("no harnesses match '{query}'\n"));
+    }
+    if style::plain() {
+        let lines = rows
+            .iter()
+            .map(|row| format!("{} - {} ({})\n", row[0], row[2], row[1]));
+        return Ok(lines.collect());
+    }
+    let title = format!("Search: {query}");
+    Ok(table::render(
+        &title,
+        &["NAME", "STATUS", "DESCRIPTION"],
+        &rows,
+    ))
+}
+
+pub fn matches<'a>(query: &str, harnesses: &'a [Harness]) -> Vec<&'a Harness> {
+    let mut scored = harnesses
+        .iter()
+        .filter_map(|harness| score(query, harness).map(|score| (score, harness)))
+        .collect::<Vec<_>>();
+    scored.sort_by(|left, right| left.0.cmp(&right.0).then(left.1.name.cmp(&right.1.name)));
+    scored.into_iter().map(|(_, harness)| harness).collect()
+}
+
+fn score(query: &str, harness: &Harness) -> Option<u8> {
+    let name = harness.name.to_lowercase();
+    let text = format!("{} {}", harness.display, harness.description).to_lowercase();
+    let query = query.to_lowercase();
+    let terms = query.split_whitespace().collect::<Vec<_>>();
+    match query.as_str() {
+        query if name == query => Some(0),
+        query if name.starts_with(query) => Some(1),
+        query if name.contains(query) => Some(2),
+        _ if terms
+            .iter()
+            .all(|term| name.contains(term) || text.contains(term)) =>
+        {
+            Some(3)
+        }
+        query if subsequence(query, &name) => Some(4),
+        _ => None,
+    }
+}
+
+fn subsequence(query: &str, name: &str) -> bool {
+    let mut chars = name.chars();
+    query.chars().all(|wanted| chars.any(|char| char == wanted))
+}
+
+#[cfg(test)]
+#[path = "search_test.rs"]
+mod tests;
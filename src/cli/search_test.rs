@@ -0,0 +1,53 @@
+use super::*;
+use crate::contracts::EnvMode;
+
+fn harness(name: &str, description: &str) -> Harness {
+    Harness {
+        name: name.to_string(),
+        display: name.to_string(),
+        description: description.to_string(),
+        binary: name.to_string(),
+        env_mode: EnvMode::None,
+        env: vec![],
+        full_screen: false,
+        project_args: vec![],
+        capabilities: vec![],
+    }
+}
+
+fn catalog() -> Vec<Harness> {
+    vec![
+        harness("opencode", "Open source terminal coding agent"),
+        harness("code", "Every Code fork of Codex"),
+        harness("codex", "OpenAI coding agent CLI"),
+        harness("gemini", "Google Gemini CLI"),
+        harness("aider", "AI pair programming in your terminal"),
+    ]
+}
+
+fn names(query: &str) -> Vec<String> {
+    let harnesses = catalog();
+    matches(query, &harnesses)
+        .iter()
+        .map(|harness| harness.name.clone())
+        .collect()
+}
+
+#[test]
+fn exact_and_prefix_name_matches_rank_first() {
+    assert_eq!(names("code"), ["code", "codex", "opencode"]);
+    assert_eq!(names("CODEX"), ["codex", "code"]);
+}
+
+#[test]
+fn descriptions_are_searched_for_every_term() {
+    assert_eq!(names("google"), ["gemini"]);
+    assert_eq!(names("terminal agent"), ["opencode"]);
+    assert_eq!(names("pair terminal"), ["aider"]);
+}
+
+#[test]
+fn abbreviations_match_as_subsequences_of_the_name() {
+    assert_eq!(names("gmn"), ["gemini"]);
+    assert!(names("zzz").is_empty());
+}
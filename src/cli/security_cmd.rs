@@ -0,0 +1,20 @@
+use super::output;
+use crate::contracts::{Capability, Harness};
+
+const USAGE: &str = "usage: terminal-jarvis security [status|audit|harness]";
+
+pub fn handle(words: &[String], harnesses: &[Harness]) -> Result<(i32, String), String> {
+    match words {
+        [] => Ok((0, output::status(harnesses))),
+        [action] if action == "status" => Ok((0, output::status(harnesses))),
+        [action] if action == "audit" => Ok((0, output::audit(harnesses))),
+        [name] => {
+            let harness = harnesses
+                .iter()
+                .find(|harness| &harness.name == name)
+                .ok_or(USAGE)?;
+            Ok((0, output::plan(harness, Capability::Security)))
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
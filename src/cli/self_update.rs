@@ -1,48 +1,14 @@
-use super::{style, table};
+use super::{install_method, style, table};
 use std::process::{Command, Stdio};
 
 pub fn run(dry_run: bool) -> Result<(i32, String), String> {
-    let (command, args) = update_command();
+    let (command, args) = install_method::detect()?;
     if dry_run {
         return Ok((0, dry_run_output(command, args)));
     }
     run_cmd(command, args)
 }
 
-fn update_command() -> (&'static str, &'static [&'static str]) {
-    if wrapper_path().is_some() {
-        return ("npm", &["install", "-g", "terminal-jarvis@latest"]);
-    }
-    let raw = std::env::var("TERMINAL_JARVIS_DISTRIBUTION").unwrap_or_default();
-    match raw.as_str() {
-        "github-release" | "github-release-cache" => {
-            return ("npm", &["install", "-g", "terminal-jarvis@latest"])
-        }
-        _ => {}
-    }
-    let path = std::env::current_exe()
-        .ok()
-        .map(|binary| binary.to_string_lossy().to_string())
-        .unwrap_or_default();
-    if homebrew_path(&path) {
-        return ("brew", &["upgrade", "terminal-jarvis"]);
-    }
-    ("cargo", &["install", "terminal-jarvis"])
-}
-
-fn homebrew_path(path: &str) -> bool {
-    path.contains("homebrew") || path.contains("Cellar")
-}
-
-fn wrapper_path() -> Option<std::path::PathBuf> {
-    let wrapper = std::env::var("TERMINAL_JARVIS_WRAPPER").ok()?;
-    let pkg = std::path::Path::new(&wrapper)
-        .parent()
-        .and_then(std::path::Path::parent)?
-        .join("package.json");
-    pkg.exists().then_some(pkg)
-}
-
 fn run_cmd(cmd: &str, args: &[&str]) -> Result<(i32, String), String> {
     let mut command = Command::new(cmd);
     command.args(args).stderr(Stdio::piped());
@@ -55,7 +21,7 @@ fn run_cmd(cmd: &str, args: &[&str]) -> Result<(i32, String), String> {
     let code = output.status.code().unwrap_or(1);
     let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
     if code == 0 {
-        Ok((0, success_output(cmd)))
+        Ok((0, success_output(cmd, installed_version())))
     } else {
         Err(format!(
             "'{} {}' exited with {code}{}",
@@ -78,14 +44,27 @@ fn dry_run_output(command: &str, args: &[&str]) -> String {
     table::fields("Self-Update Plan", &[("COMMAND", value)])
 }
 
-fn success_output(command: &str) -> String {
+fn installed_version() -> Option<String> {
+    let output = Command::new("terminal-jarvis")
+        .arg("--version")
+        .output()
+        .ok()?;
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !version.is_empty()).then_some(version)
+}
+
+fn success_output(command: &str, version: Option<String>) -> String {
+    let version = version.unwrap_or_else(|| "unknown".to_string());
     if style::plain() {
-        return format!("terminal-jarvis updated via {command}\n");
+        return format!("terminal-jarvis updated via {command}: {version}\n");
     }
     format!(
         "{}\n{}",
         style::success("Terminal Jarvis updated"),
-        table::fields("Self-Update", &[("METHOD", command.to_string())])
+        table::fields(
+            "Self-Update",
+            &[("METHOD", command.to_string()), ("VERSION", version)]
+        )
     )
 }
 
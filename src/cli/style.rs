@@ -50,13 +50,16 @@ pub fn banner(title: &str, subtitle: &str) -> String {
     format!("{}\n{}\n\n", heading(title), paint(subtitle, "2"))
 }
 
+pub fn dumb_terminal() -> bool {
+    term_is_dumb(std::env::var("TERM").ok().as_deref())
+}
+
 fn paint(value: &str, code: &str) -> String {
-    let term = std::env::var("TERM").ok();
     if color_enabled_for(
         std::io::stdout().is_terminal(),
         OPTIONS.with(|cell| cell.get().no_color),
         std::env::var_os("NO_COLOR").is_some(),
-        term_is_dumb(term.as_deref()),
+        dumb_terminal(),
     ) {
         format!("\x1b[{code}m{value}\x1b[0m")
     } else {
@@ -0,0 +1,38 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static LEVEL: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verbosity {
+    Quiet,
+    Normal,
+    Verbose,
+}
+
+pub fn set(verbosity: Verbosity) -> Verbosity {
+    from(LEVEL.swap(verbosity as u8, Ordering::Relaxed))
+}
+
+pub fn get() -> Verbosity {
+    from(LEVEL.load(Ordering::Relaxed))
+}
+
+pub fn notice(message: &str) {
+    if get() != Verbosity::Quiet {
+        eprintln!("{message}");
+    }
+}
+
+pub fn detail(message: &str) {
+    if get() == Verbosity::Verbose {
+        eprintln!("{message}");
+    }
+}
+
+fn from(level: u8) -> Verbosity {
+    match level {
+        0 => Verbosity::Quiet,
+        2 => Verbosity::Verbose,
+        _ => Verbosity::Normal,
+    }
+}
@@ -1,4 +1,5 @@
 use super::{style, table};
+use crate::{context, jarvis};
 use std::path::Path;
 
 const REPO: &str = "https://github.com/BA-CalderonMorales/terminal-jarvis";
@@ -19,7 +20,7 @@ pub fn text(verbose: bool, catalog: &Path, home: &Path) -> String {
         .unwrap_or_else(|_| "unknown".to_string());
     let git_sha = option_env!("TERMINAL_JARVIS_GIT_SHA").unwrap_or("unknown");
     let distribution = nonempty_env("TERMINAL_JARVIS_DISTRIBUTION", || "unknown".to_string());
-    let wrapper = std::env::var("TERMINAL_JARVIS_WRAPPER").unwrap_or_default();
+    let wrapper = context::environment().wrapper.clone().unwrap_or_default();
     let release = nonempty_env("TERMINAL_JARVIS_RELEASE_URL", || {
         format!("{REPO}/releases/tag/v{version}")
     });
@@ -32,6 +33,13 @@ pub fn text(verbose: bool, catalog: &Path, home: &Path) -> String {
         ("CACHE", cache),
         ("CATALOG", catalog.display().to_string()),
         ("HOME", home.display().to_string()),
+        ("ENVIRONMENT", context::environment().summary()),
+        ("TARGET", target()),
+        (
+            "NODE PM",
+            jarvis::node_manager(context::node_pm().as_deref())
+                .map_or_else(|error| error, String::from),
+        ),
     ];
     if !wrapper.is_empty() {
         details.push(("WRAPPER", wrapper));
@@ -42,6 +50,11 @@ pub fn text(verbose: bool, catalog: &Path, home: &Path) -> String {
     table::fields(&format!("Terminal Jarvis {version}"), &details)
 }
 
+fn target() -> String {
+    let target = context::detect_target();
+    format!("{target} ({})", target.release_platform())
+}
+
 fn nonempty_env<F>(key: &str, fallback: F) -> String
 where
     F: FnOnce() -> String,
@@ -61,10 +74,8 @@ fn distribution_channel() -> String {
             other => other.to_string(),
         };
     }
-    if let Ok(wrapper) = std::env::var("TERMINAL_JARVIS_WRAPPER") {
-        if !wrapper.is_empty() {
-            return "npm".to_string();
-        }
+    if context::environment().wrapper.is_some() {
+        return "npm".to_string();
     }
     homebrew_path(
         &std::env::current_exe()
@@ -8,7 +8,6 @@ fn with_env<F: FnOnce() -> T, T>(f: F) -> T {
 
 fn clear() {
     std::env::remove_var("TERMINAL_JARVIS_DISTRIBUTION");
-    std::env::remove_var("TERMINAL_JARVIS_WRAPPER");
     std::env::remove_var("TERMINAL_JARVIS_RELEASE_URL");
     std::env::remove_var("TERMINAL_JARVIS_CACHE");
 }
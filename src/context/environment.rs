@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EnvironmentContext {
+    pub is_codespaces: bool,
+    pub is_headless: bool,
+    pub is_ssh: bool,
+    pub is_container: bool,
+    pub is_ci: bool,
+    pub has_display: bool,
+    pub is_npx: bool,
+    pub is_root: bool,
+    pub wrapper: Option<String>,
+}
+
+impl EnvironmentContext {
+    pub fn from_vars(vars: &BTreeMap<String, String>, is_root: bool) -> Self {
+        let get = |key: &str| {
+            vars.get(key)
+                .map(String::as_str)
+                .filter(|value| !value.is_empty())
+        };
+        let set = |keys: &[&str]| keys.iter().any(|key| get(key).is_some());
+        let is_codespaces = get("CODESPACES") == Some("true")
+            || set(&["CODESPACE_NAME", "GITHUB_CODESPACES_PORT_FORWARDING_DOMAIN"]);
+        let is_ssh = set(&["SSH_CONNECTION", "SSH_CLIENT", "SSH_TTY"]);
+        let is_container = set(&["container", "REMOTE_CONTAINERS", "DEVCONTAINER"]);
+        let is_ci = get("CI").is_some_and(|value| value != "false" && value != "0")
+            || get("GITHUB_ACTIONS") == Some("true");
+        let has_display = set(&["DISPLAY", "WAYLAND_DISPLAY"]);
+        let wrapper = get("TERMINAL_JARVIS_WRAPPER").map(str::to_string);
+        let is_npx = get("npm_command") == Some("exec")
+            || wrapper.as_ref().is_some_and(|path| path.contains("_npx"));
+        Self {
+            is_codespaces,
+            is_headless: is_codespaces || is_ci || ((is_ssh || is_container) && !has_display),
+            is_ssh,
+            is_container,
+            is_ci,
+            has_display,
+            is_npx,
+            is_root,
+            wrapper,
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        let flags = [
+            (self.is_codespaces, "codespaces"),
+            (self.is_ssh, "ssh"),
+            (self.is_container, "container"),
+            (self.is_ci, "ci"),
+            (self.is_headless, "headless"),
+            (self.is_npx, "npx"),
+            (self.is_root, "root"),
+        ];
+        let names = flags
+            .iter()
+            .filter(|(on, _)| *on)
+            .map(|(_, name)| *name)
+            .collect::<Vec<_>>();
+        if names.is_empty() {
+            "local".to_string()
+        } else {
+            names.join(", ")
+        }
+    }
+}
+
+pub fn environment() -> &'static EnvironmentContext {
+    static CONTEXT: OnceLock<EnvironmentContext> = OnceLock::new();
+    CONTEXT.get_or_init(|| {
+        let vars = std::env::vars().collect();
+        let mut context = EnvironmentContext::from_vars(&vars, crate::security::running_as_root());
+        if !context.is_container && std::path::Path::new("/.dockerenv").exists() {
+            context.is_container = true;
+            context.is_headless |= !context.has_display;
+        }
+        context
+    })
+}
+
+#[cfg(test)]
+#[path = "environment_test.rs"]
+mod tests;
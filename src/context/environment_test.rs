@@ -0,0 +1,84 @@
+use super::EnvironmentContext;
+use std::collections::BTreeMap;
+
+fn detect(pairs: &[(&str, &str)]) -> EnvironmentContext {
+    let vars = pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect::<BTreeMap<_, _>>();
+    EnvironmentContext::from_vars(&vars, false)
+}
+
+#[test]
+fn empty_environment_is_a_local_desktop_without_display() {
+    let context = detect(&[]);
+    assert_eq!(context, EnvironmentContext::default());
+    assert_eq!(context.summary(), "local");
+}
+
+#[test]
+fn codespaces_is_detected_from_any_of_its_variables() {
+    for pairs in [
+        &[("CODESPACES", "true")][..],
+        &[("CODESPACE_NAME", "octo-space")][..],
+        &[("GITHUB_CODESPACES_PORT_FORWARDING_DOMAIN", "app.github.dev")][..],
+    ] {
+        let context = detect(pairs);
+        assert!(context.is_codespaces && context.is_headless, "{pairs:?}");
+    }
+    assert!(!detect(&[("CODESPACES", "false")]).is_codespaces);
+}
+
+#[test]
+fn ssh_and_containers_are_headless_only_without_a_display() {
+    for key in [
+        "SSH_CONNECTION",
+        "SSH_CLIENT",
+        "SSH_TTY",
+        "container",
+        "REMOTE_CONTAINERS",
+    ] {
+        assert!(detect(&[(key, "1")]).is_headless, "{key}");
+        for display in ["DISPLAY", "WAYLAND_DISPLAY"] {
+            let context = detect(&[(key, "1"), (display, ":0")]);
+            assert!(
+                context.has_display && !context.is_headless,
+                "{key} {display}"
+            );
+        }
+    }
+    assert!(detect(&[("SSH_TTY", "1"), ("DISPLAY", "")]).is_headless);
+}
+
+#[test]
+fn ci_is_headless_even_with_a_display() {
+    for pairs in [
+        &[("CI", "true")][..],
+        &[("CI", "1")][..],
+        &[("GITHUB_ACTIONS", "true")][..],
+    ] {
+        let context = detect(&[pairs, &[("DISPLAY", ":0")]].concat());
+        assert!(context.is_ci && context.is_headless, "{pairs:?}");
+    }
+    assert!(!detect(&[("CI", "false")]).is_ci);
+    assert!(!detect(&[("CI", "0")]).is_ci);
+}
+
+#[test]
+fn npx_and_root_are_reported() {
+    assert!(detect(&[("npm_command", "exec")]).is_npx);
+    assert!(detect(&[("TERMINAL_JARVIS_WRAPPER", "/home/u/.npm/_npx/1/bin/tj.js")]).is_npx);
+    let global = detect(&[("TERMINAL_JARVIS_WRAPPER", "/usr/lib/node_modules/tj/bin.js")]);
+    assert!(!global.is_npx);
+    assert_eq!(
+        global.wrapper.as_deref(),
+        Some("/usr/lib/node_modules/tj/bin.js")
+    );
+    assert_eq!(detect(&[("TERMINAL_JARVIS_WRAPPER", "")]).wrapper, None);
+    let root = EnvironmentContext::from_vars(&BTreeMap::new(), true);
+    assert_eq!(root.summary(), "root");
+    assert_eq!(
+        detect(&[("SSH_TTY", "1"), ("CI", "true")]).summary(),
+        "ssh, ci, headless"
+    );
+}
@@ -0,0 +1,98 @@
+use super::clock::{ago, now};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const FILE: &str = "history.tsv";
+const MAX: usize = 200;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Launch {
+    pub at: u64,
+    pub harness: String,
+    pub capability: String,
+    pub code: Option<i32>,
+    pub seconds: Option<u64>,
+    pub args: String,
+}
+
+impl Launch {
+    pub fn new(harness: &str, capability: &str) -> Self {
+        Self {
+            at: now(),
+            harness: harness.to_string(),
+            capability: capability.to_string(),
+            ..Self::default()
+        }
+    }
+
+    pub fn age(&self) -> String {
+        ago(now().saturating_sub(self.at))
+    }
+}
+
+pub fn record_launch(home: &Path, launch: &Launch) -> io::Result<()> {
+    let mut lines = fs::read_to_string(home.join(FILE)).unwrap_or_default();
+    let optional = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+    lines.push_str(&format!(
+        "{}\t{}\t{}\t{}\t{}\t{}\n",
+        launch.at,
+        launch.harness,
+        launch.capability,
+        optional(launch.code.map(|code| code.to_string())),
+        optional(launch.seconds.map(|seconds| seconds.to_string())),
+        launch.args.replace(['\t', '\n'], " ")
+    ));
+    let kept = lines.lines().rev().take(MAX).collect::<Vec<_>>();
+    let data = kept
+        .into_iter()
+        .rev()
+        .map(|line| format!("{line}\n"))
+        .collect::<String>();
+    fs::create_dir_all(home)?;
+    fs::write(home.join(FILE), data)
+}
+
+pub fn history(home: &Path) -> Vec<Launch> {
+    let data = fs::read_to_string(home.join(FILE)).unwrap_or_default();
+    data.lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            Some(Launch {
+                at: fields.next()?.parse().ok()?,
+                harness: fields.next()?.to_string(),
+                capability: fields.next()?.to_string(),
+                code: fields.next().and_then(|code| code.parse().ok()),
+                seconds: fields.next().and_then(|seconds| seconds.parse().ok()),
+                args: fields.next().unwrap_or_default().to_string(),
+            })
+        })
+        .collect()
+}
+
+pub fn last_launch<F>(home: &Path, known: F) -> Option<Launch>
+where
+    F: Fn(&str) -> bool,
+{
+    history(home)
+        .into_iter()
+        .rev()
+        .find(|launch| known(&launch.harness))
+}
+
+pub fn recent(launches: &[Launch], limit: usize) -> Vec<String> {
+    let mut names = Vec::new();
+    for launch in launches.iter().rev() {
+        if names.len() == limit {
+            break;
+        }
+        if !names.contains(&launch.harness) {
+            names.push(launch.harness.clone());
+        }
+    }
+    names
+}
+
+#[cfg(test)]
+#[path = "history_test.rs"]
+mod tests;
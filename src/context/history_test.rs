@@ -0,0 +1,77 @@
+use super::*;
+
+fn home(name: &str) -> std::path::PathBuf {
+    let home = std::env::temp_dir().join(format!("tj-history-{}-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&home);
+    home
+}
+
+#[test]
+fn launches_are_appended_and_capped() {
+    let home = home("cap");
+    assert!(history(&home).is_empty());
+    for index in 0..MAX + 5 {
+        record_launch(&home, &Launch::new(&format!("h{index}"), "ui")).unwrap();
+    }
+    let launches = history(&home);
+    assert_eq!(launches.len(), MAX);
+    assert_eq!(launches[0].harness, "h5");
+    assert_eq!(launches[MAX - 1].harness, format!("h{}", MAX + 4));
+    assert_eq!(launches[0].capability, "ui");
+    fs::remove_dir_all(home).unwrap();
+}
+
+#[test]
+fn last_launch_skips_harnesses_that_left_the_catalog_and_bad_lines() {
+    let home = home("last");
+    fs::create_dir_all(&home).unwrap();
+    fs::write(
+        home.join(FILE),
+        "10\tcodex\tui\nnot a line\n20\tgone\theadless\n",
+    )
+    .unwrap();
+    let last = last_launch(&home, |name| name != "gone").unwrap();
+    assert_eq!((last.at, last.harness.as_str()), (10, "codex"));
+    assert_eq!(last_launch(&home, |_| false), None);
+    fs::remove_dir_all(home).unwrap();
+}
+
+#[test]
+fn outcomes_round_trip_and_older_lines_still_parse() {
+    let home = home("outcome");
+    fs::create_dir_all(&home).unwrap();
+    fs::write(home.join(FILE), "10\tcodex\tui\n").unwrap();
+    let launch = Launch {
+        code: Some(3),
+        seconds: Some(42),
+        args: "fix\tthe tests".to_string(),
+        ..Launch::new("aider", "headless")
+    };
+    record_launch(&home, &launch).unwrap();
+    let launches = history(&home);
+    assert_eq!((launches[0].code, launches[0].seconds), (None, None));
+    assert_eq!(launches[1].code, Some(3));
+    assert_eq!(launches[1].seconds, Some(42));
+    assert_eq!(launches[1].args, "fix the tests");
+    fs::remove_dir_all(home).unwrap();
+}
+
+#[test]
+fn recent_names_are_deduplicated_newest_first() {
+    let launch = |at, harness: &str| Launch {
+        at,
+        harness: harness.to_string(),
+        capability: "ui".to_string(),
+        ..Launch::default()
+    };
+    let launches = [
+        launch(1, "aider"),
+        launch(2, "codex"),
+        launch(3, "claude"),
+        launch(4, "codex"),
+        launch(5, "pi"),
+    ];
+    assert_eq!(recent(&launches, 3), ["pi", "codex", "claude"]);
+    assert_eq!(recent(&launches, 10), ["pi", "codex", "claude", "aider"]);
+    assert!(recent(&[], 3).is_empty());
+}
@@ -0,0 +1,59 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const KEEP: usize = 10;
+
+pub fn stderr_log(home: &Path, harness: &str) -> io::Result<PathBuf> {
+    let dir = home.join("logs");
+    fs::create_dir_all(&dir)?;
+    let mut stamps = stamps(&dir, harness)?;
+    stamps.sort_unstable();
+    let excess = stamps.len().saturating_sub(KEEP - 1);
+    for stamp in &stamps[..excess] {
+        fs::remove_file(dir.join(format!("{harness}.{stamp}.log")))?;
+    }
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis())
+        .unwrap_or_default();
+    Ok(dir.join(format!("{harness}.{now}.log")))
+}
+
+fn stamps(dir: &Path, harness: &str) -> io::Result<Vec<u128>> {
+    let prefix = format!("{harness}.");
+    let mut stamps = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let name = entry?.file_name().to_string_lossy().to_string();
+        let stamp = name
+            .strip_prefix(&prefix)
+            .and_then(|rest| rest.strip_suffix(".log"))
+            .and_then(|stamp| stamp.parse::<u128>().ok());
+        stamps.extend(stamp);
+    }
+    Ok(stamps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn old_logs_for_the_same_harness_are_rotated_out() {
+        let home = std::env::temp_dir().join(format!("tj-logs-{}", std::process::id()));
+        let dir = home.join("logs");
+        fs::create_dir_all(&dir).unwrap();
+        for stamp in 1..=12 {
+            fs::write(dir.join(format!("codex.{stamp}.log")), "").unwrap();
+        }
+        fs::write(dir.join("code.1.log"), "").unwrap();
+        let next = stderr_log(&home, "codex").unwrap();
+        assert!(next.starts_with(&dir));
+        let mut left = stamps(&dir, "codex").unwrap();
+        left.sort_unstable();
+        assert_eq!(left, (4..=12).collect::<Vec<_>>());
+        assert_eq!(stamps(&dir, "code").unwrap(), [1]);
+        fs::remove_dir_all(home).unwrap();
+    }
+}
@@ -1,5 +1,17 @@
+mod clock;
+mod environment;
 mod gates;
+mod history;
+mod logs;
+mod platform;
+mod project;
 mod session;
 
+pub use clock::{iso8601, now};
+pub use environment::{environment, EnvironmentContext};
 pub use gates::gates_root;
-pub use session::{catalog_root, default_home, load, save, Session};
+pub use history::{history, last_launch, recent, record_launch, Launch};
+pub use logs::stderr_log;
+pub use platform::{detect_target, TargetTriple};
+pub use project::{project, ProjectConfig, ToolDefaults, PROJECT_FILE};
+pub use session::{catalog_root, default_home, load, node_pm, save, Session};
@@ -0,0 +1,79 @@
+use std::fmt;
+use std::path::Path;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TargetTriple {
+    pub arch: String,
+    pub os: String,
+    pub libc: Option<String>,
+}
+
+impl TargetTriple {
+    pub fn parse(value: &str) -> Option<Self> {
+        let parts = value.split('-').collect::<Vec<_>>();
+        let (arch, os, libc) = match parts.as_slice() {
+            [arch, "apple", "darwin"] => (arch, "macos", None),
+            [arch, "pc", "windows", libc] => (arch, "windows", Some(*libc)),
+            [arch, "unknown", "linux", libc] => (arch, "linux", Some(*libc)),
+            _ => return None,
+        };
+        Some(Self {
+            arch: arch.to_string(),
+            os: os.to_string(),
+            libc: libc.map(str::to_string),
+        })
+    }
+
+    pub fn release_platform(&self) -> String {
+        let arch = match self.arch.as_str() {
+            "x86_64" => "x64",
+            "aarch64" => "arm64",
+            other => other,
+        };
+        match (self.os.as_str(), &self.libc) {
+            ("linux", Some(libc)) => format!("linux-{arch}-{libc}"),
+            ("windows", _) => format!("win32-{arch}"),
+            (os, _) => format!("{os}-{arch}"),
+        }
+    }
+}
+
+impl fmt::Display for TargetTriple {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let libc = self.libc.as_deref().unwrap_or_default();
+        match self.os.as_str() {
+            "macos" => write!(formatter, "{}-apple-darwin", self.arch),
+            "windows" => write!(formatter, "{}-pc-windows-{libc}", self.arch),
+            "linux" => write!(formatter, "{}-unknown-linux-{libc}", self.arch),
+            os => write!(formatter, "{}-unknown-{os}", self.arch),
+        }
+    }
+}
+
+pub fn detect_target() -> TargetTriple {
+    let os = std::env::consts::OS;
+    let libc = match os {
+        "linux" if musl_host(Path::new("/lib")) => Some("musl"),
+        "linux" => Some("gnu"),
+        "windows" => Some("msvc"),
+        _ => None,
+    };
+    TargetTriple {
+        arch: std::env::consts::ARCH.to_string(),
+        os: os.to_string(),
+        libc: libc.map(str::to_string),
+    }
+}
+
+fn musl_host(lib: &Path) -> bool {
+    cfg!(target_env = "musl")
+        || std::fs::read_dir(lib).is_ok_and(|entries| {
+            entries
+                .flatten()
+                .any(|entry| entry.file_name().to_string_lossy().starts_with("ld-musl-"))
+        })
+}
+
+#[cfg(test)]
+#[path = "platform_test.rs"]
+mod tests;
@@ -0,0 +1,40 @@
+use super::*;
+
+#[test]
+fn common_triples_round_trip() {
+    for triple in [
+        "x86_64-unknown-linux-gnu",
+        "x86_64-unknown-linux-musl",
+        "aarch64-unknown-linux-musl",
+        "aarch64-apple-darwin",
+        "x86_64-pc-windows-msvc",
+    ] {
+        assert_eq!(TargetTriple::parse(triple).unwrap().to_string(), triple);
+    }
+    assert_eq!(TargetTriple::parse("wasm32"), None);
+}
+
+#[test]
+fn triples_map_to_release_archive_platforms() {
+    let platform = |triple: &str| TargetTriple::parse(triple).unwrap().release_platform();
+    assert_eq!(platform("x86_64-unknown-linux-gnu"), "linux-x64-gnu");
+    assert_eq!(platform("aarch64-unknown-linux-musl"), "linux-arm64-musl");
+    assert_eq!(platform("aarch64-apple-darwin"), "macos-arm64");
+    assert_eq!(platform("x86_64-pc-windows-msvc"), "win32-x64");
+}
+
+#[test]
+fn detected_target_uses_the_running_arch_and_os() {
+    let target = detect_target();
+    assert_eq!(target.arch, std::env::consts::ARCH);
+    assert_eq!(target.libc.is_some(), target.os != "macos");
+}
+
+#[test]
+fn musl_is_detected_from_its_dynamic_loader() {
+    let lib = std::env::temp_dir().join(format!("terminal-jarvis-musl-{}", std::process::id()));
+    std::fs::create_dir_all(&lib).unwrap();
+    std::fs::write(lib.join("ld-musl-x86_64.so.1"), "").unwrap();
+    assert!(musl_host(&lib));
+    std::fs::remove_dir_all(&lib).unwrap();
+}
@@ -0,0 +1,95 @@
+use crate::catalog::parser;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const PROJECT_FILE: &str = ".terminal-jarvis.toml";
+const MAX_BYTES: u64 = 16 * 1024;
+const ALLOWED: &str = "preferred, <harness>.args, <harness>.env";
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ToolDefaults {
+    pub args: Vec<String>,
+    pub env: Vec<(String, String)>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProjectConfig {
+    pub path: PathBuf,
+    pub preferred: Option<String>,
+    pub tools: BTreeMap<String, ToolDefaults>,
+}
+
+pub fn discover(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(PROJECT_FILE))
+        .find(|path| path.is_file())
+}
+
+pub fn project(start: &Path) -> Result<Option<ProjectConfig>, String> {
+    let Some(path) = discover(start) else {
+        return Ok(None);
+    };
+    let shown = path.display().to_string();
+    let size = fs::metadata(&path).map_err(|error| format!("{shown}: {error}"))?;
+    if size.len() > MAX_BYTES {
+        return Err(format!("{shown}: larger than {MAX_BYTES} bytes"));
+    }
+    let text = fs::read_to_string(&path).map_err(|error| format!("{shown}: {error}"))?;
+    parse(&path, &text)
+        .map(Some)
+        .map_err(|error| format!("{shown}: {error}"))
+}
+
+pub fn parse(path: &Path, text: &str) -> Result<ProjectConfig, String> {
+    let fields = parser::parse(text)?;
+    let mut config = ProjectConfig {
+        path: path.to_path_buf(),
+        ..ProjectConfig::default()
+    };
+    for key in fields.keys() {
+        if key == "preferred" {
+            config.preferred = Some(parser::string(&fields, key)?);
+            continue;
+        }
+        let Some((tool, setting)) = key.split_once('.') else {
+            return Err(refused(key));
+        };
+        let defaults = config.tools.entry(tool.to_string()).or_default();
+        match setting {
+            "args" => defaults.args = parser::list(&fields, key)?,
+            "env" => {
+                let pairs = parser::list(&fields, key)?
+                    .into_iter()
+                    .map(|pair| env_pair(key, &pair));
+                defaults.env = pairs.collect::<Result<_, _>>()?;
+            }
+            _ => return Err(refused(key)),
+        }
+    }
+    Ok(config)
+}
+
+fn refused(key: &str) -> String {
+    format!("'{key}' cannot be set by a project file; allowed keys are {ALLOWED}")
+}
+
+fn env_pair(key: &str, pair: &str) -> Result<(String, String), String> {
+    let (name, value) = pair
+        .split_once('=')
+        .ok_or_else(|| format!("'{key}' entries must be \"NAME=value\", got \"{pair}\""))?;
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_');
+    if !valid {
+        return Err(format!("'{key}' has invalid variable name '{name}'"));
+    }
+    Ok((name.to_string(), value.to_string()))
+}
+
+#[cfg(test)]
+#[path = "project_test.rs"]
+mod tests;
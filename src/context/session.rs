@@ -35,6 +35,10 @@ pub fn catalog_root() -> PathBuf {
         .unwrap_or_else(|| PathBuf::from("harnesses"))
 }
 
+pub fn node_pm() -> Option<String> {
+    env::var("TERMINAL_JARVIS_NODE_PM").ok()
+}
+
 fn catalog_candidates() -> Vec<PathBuf> {
     let mut candidates = Vec::new();
     if let Ok(cwd) = env::current_dir() {
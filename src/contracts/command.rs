@@ -2,11 +2,35 @@
 pub struct CommandPlan {
     pub command: String,
     pub args: Vec<String>,
+    pub requires: Vec<String>,
+    pub default_args: Vec<String>,
 }
 
 impl CommandPlan {
     pub fn new(command: String, args: Vec<String>) -> Self {
-        Self { command, args }
+        Self {
+            command,
+            args,
+            requires: Vec::new(),
+            default_args: Vec::new(),
+        }
+    }
+
+    pub fn with_requires(mut self, requires: Vec<String>) -> Self {
+        self.requires = requires;
+        self
+    }
+
+    pub fn with_default_args(mut self, default_args: Vec<String>) -> Self {
+        self.default_args = default_args;
+        self
+    }
+
+    pub fn args_for<'a>(&'a self, extra: &'a [String]) -> &'a [String] {
+        match extra.is_empty() {
+            true => &self.default_args,
+            false => extra,
+        }
     }
 
     pub fn render(&self) -> String {
@@ -15,6 +15,8 @@ pub struct Harness {
     pub binary: String,
     pub env_mode: EnvMode,
     pub env: Vec<String>,
+    pub full_screen: bool,
+    pub project_args: Vec<String>,
     pub capabilities: Vec<CapabilityPlan>,
 }
 
@@ -0,0 +1,22 @@
+use std::path::PathBuf;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LaunchOptions {
+    pub env: Vec<(String, String)>,
+    pub cwd: Option<PathBuf>,
+    pub stdin: bool,
+    pub sandbox: bool,
+    pub log_stderr: bool,
+    pub stderr_log: Option<PathBuf>,
+    pub output_log: Option<PathBuf>,
+    pub quiet_output: bool,
+    pub inherit_stderr: bool,
+    pub print_env: bool,
+    pub require_auth: bool,
+}
+
+impl LaunchOptions {
+    pub fn provides(&self, name: &str) -> bool {
+        self.env.iter().any(|(key, _)| key == name)
+    }
+}
@@ -2,8 +2,14 @@ mod capability;
 mod command;
 mod environment;
 mod harness;
+mod launch;
+mod outcome;
+mod status;
 
 pub use capability::Capability;
 pub use command::CommandPlan;
 pub use environment::EnvMode;
 pub use harness::{CapabilityPlan, Harness};
+pub use launch::LaunchOptions;
+pub use outcome::{JarvisError, Outcome};
+pub use status::ToolStatus;
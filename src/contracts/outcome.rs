@@ -0,0 +1,37 @@
+use std::fmt;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Outcome {
+    pub code: i32,
+    pub output: String,
+}
+
+impl Outcome {
+    pub fn new(code: i32, output: String) -> Self {
+        Self { code, output }
+    }
+
+    pub fn success(&self) -> bool {
+        self.code == 0
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum JarvisError {
+    Catalog(String),
+    UnknownHarness(String),
+    Refused(String),
+}
+
+impl fmt::Display for JarvisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownHarness(name) => write!(f, "unknown harness '{name}'"),
+            Self::Catalog(message) | Self::Refused(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for JarvisError {}
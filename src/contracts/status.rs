@@ -0,0 +1,14 @@
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ToolStatus {
+    pub name: String,
+    pub display: String,
+    pub installed: bool,
+    pub missing_env: Vec<String>,
+}
+
+impl ToolStatus {
+    pub fn ready(&self) -> bool {
+        self.installed && self.missing_env.is_empty()
+    }
+}
@@ -0,0 +1,94 @@
+use crate::cli::{guard, resolve, verbosity};
+use crate::contracts::{Capability, Harness, JarvisError, LaunchOptions, Outcome, ToolStatus};
+use crate::Verbosity;
+use std::path::{Path, PathBuf};
+
+#[path = "jarvis_support.rs"]
+mod support;
+
+pub use support::node_manager;
+
+/// ```
+/// use std::path::Path;
+/// use terminal_jarvis::{Jarvis, JarvisError, LaunchOptions};
+///
+/// let catalog = Path::new(env!("CARGO_MANIFEST_DIR")).join("harnesses");
+/// let home = std::env::temp_dir().join("terminal-jarvis-doc");
+/// let jarvis = Jarvis::open(&catalog, &home, Some("npm")).unwrap();
+/// let tools = jarvis.list_tools();
+/// assert!(tools.iter().any(|tool| tool.name == "codex"));
+/// let launched = jarvis.launch("ghost", &[], LaunchOptions::default());
+/// assert_eq!(launched, Err(JarvisError::UnknownHarness("ghost".into())));
+/// ```
+pub struct Jarvis {
+    home: PathBuf,
+    harnesses: Vec<Harness>,
+    warnings: Vec<String>,
+}
+
+impl Jarvis {
+    pub fn open(
+        catalog_root: &Path,
+        home: &Path,
+        node_pm: Option<&str>,
+    ) -> Result<Self, JarvisError> {
+        let (harnesses, warnings) = support::harnesses(catalog_root, node_pm)?;
+        let home = home.to_path_buf();
+        Ok(Self {
+            home,
+            harnesses,
+            warnings,
+        })
+    }
+
+    pub fn set_verbosity(level: Verbosity) -> Verbosity {
+        verbosity::set(level)
+    }
+
+    pub fn harnesses(&self) -> &[Harness] {
+        &self.harnesses
+    }
+
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    pub fn list_tools(&self) -> Vec<ToolStatus> {
+        self.harnesses.iter().map(support::tool_status).collect()
+    }
+
+    pub fn install(&self, name: &str) -> Result<Outcome, JarvisError> {
+        self.capability(name, Capability::Download)
+    }
+
+    pub fn update(&self, name: &str) -> Result<Outcome, JarvisError> {
+        self.capability(name, Capability::Update)
+    }
+
+    pub fn launch(
+        &self,
+        name: &str,
+        args: &[String],
+        options: LaunchOptions,
+    ) -> Result<Outcome, JarvisError> {
+        support::known(&self.harnesses, name)?;
+        let invocation =
+            resolve::direct(name, args, &self.harnesses).map_err(JarvisError::Refused)?;
+        support::outcome(guard::start(
+            invocation,
+            options,
+            &self.harnesses,
+            &self.home,
+        ))
+    }
+
+    fn capability(&self, name: &str, capability: Capability) -> Result<Outcome, JarvisError> {
+        support::known(&self.harnesses, name)?;
+        support::outcome(guard::capability(
+            &self.harnesses,
+            name,
+            capability,
+            &self.home,
+        ))
+    }
+}
@@ -0,0 +1,61 @@
+use crate::contracts::{Harness, JarvisError, Outcome, ToolStatus};
+use crate::{catalog, runtime, security};
+use std::path::Path;
+
+pub fn harnesses(
+    catalog_root: &Path,
+    node_pm: Option<&str>,
+) -> Result<(Vec<Harness>, Vec<String>), JarvisError> {
+    let mut harnesses = catalog::load(catalog_root)
+        .map_err(|error| JarvisError::Catalog(catalog_error(catalog_root, error)))?;
+    let errors = catalog::validate(&harnesses);
+    if !errors.is_empty() {
+        return Err(JarvisError::Catalog(errors.join("; ")));
+    }
+    let mut warnings = Vec::new();
+    let manager = node_manager(node_pm).unwrap_or_else(|error| {
+        warnings.push(format!("{error}; using npm"));
+        "npm"
+    });
+    runtime::adapt_harnesses(&mut harnesses, manager);
+    Ok((harnesses, warnings))
+}
+
+pub fn known(harnesses: &[Harness], name: &str) -> Result<(), JarvisError> {
+    match harnesses.iter().any(|harness| harness.name == name) {
+        true => Ok(()),
+        false => Err(JarvisError::UnknownHarness(name.to_string())),
+    }
+}
+
+pub fn tool_status(harness: &Harness) -> ToolStatus {
+    ToolStatus {
+        name: harness.name.clone(),
+        display: harness.display.clone(),
+        installed: security::command_on_path(&harness.binary),
+        missing_env: security::missing_env(harness),
+    }
+}
+
+pub fn outcome(result: Result<(i32, String), String>) -> Result<Outcome, JarvisError> {
+    result
+        .map(|(code, output)| Outcome::new(code, output))
+        .map_err(JarvisError::Refused)
+}
+
+pub fn node_manager(setting: Option<&str>) -> Result<&'static str, String> {
+    runtime::node_manager(setting, security::command_on_path)
+}
+
+fn catalog_error(path: &Path, error: std::io::Error) -> String {
+    if error.kind() == std::io::ErrorKind::NotFound {
+        return format!(
+            "harness catalog is missing at {}; reinstall terminal-jarvis or set TERMINAL_JARVIS_CATALOG",
+            path.display()
+        );
+    }
+    format!(
+        "failed to load harness catalog at {}: {error}",
+        path.display()
+    )
+}
@@ -3,8 +3,13 @@ pub mod cli;
 pub mod context;
 pub mod contracts;
 pub mod gates;
+pub mod jarvis;
 pub mod runtime;
 pub mod security;
 
+pub use cli::verbosity::Verbosity;
+pub use contracts::{Capability, Harness, JarvisError, LaunchOptions, Outcome, ToolStatus};
+pub use jarvis::Jarvis;
+
 #[cfg(test)]
 pub static ENV_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
@@ -0,0 +1,72 @@
+use super::{parse_version, probe};
+use crate::contracts::{Capability, CommandPlan, Harness};
+use std::time::Duration;
+
+const LOOKUP_HOSTS: [&str; 2] = ["pypi.org", "github.com"];
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+    Npm(String),
+    PyPi(String),
+    GitHub(String),
+}
+
+pub fn source(harness: &Harness) -> Option<Source> {
+    let command = &harness.plan(Capability::Download)?.command;
+    let last = command.args.last()?;
+    match command.command.as_str() {
+        "npm" | "pnpm" | "bun" => Some(Source::Npm(package(last))),
+        "uv" | "pip" | "pipx" => Some(Source::PyPi(package(last).replace("==", ""))),
+        _ => github_repo(last).map(Source::GitHub),
+    }
+}
+
+pub fn latest(source: &Source, timeout: Duration) -> Option<String> {
+    let seconds = timeout.as_secs().max(1).to_string();
+    let (url, field) = match source {
+        Source::Npm(package) => {
+            let view = ["view", package, "version"].map(String::from).to_vec();
+            let text = probe::capture(&CommandPlan::new("npm".into(), view), timeout)?;
+            return parse_version(&text);
+        }
+        Source::PyPi(package) => (format!("https://pypi.org/pypi/{package}/json"), "version"),
+        Source::GitHub(repo) => (
+            format!("https://api.github.com/repos/{repo}/releases/latest"),
+            "tag_name",
+        ),
+    };
+    crate::security::validate_url(&url, &LOOKUP_HOSTS).ok()?;
+    let args = ["-fsSL", "--max-time", &seconds, &url]
+        .map(String::from)
+        .to_vec();
+    let body = probe::capture(&CommandPlan::new("curl".into(), args), timeout)?;
+    json_field(&body, field).and_then(|value| parse_version(&value))
+}
+
+fn package(spec: &str) -> String {
+    let spec = spec.split("==").next().unwrap_or(spec);
+    match spec.rfind('@') {
+        Some(index) if index > 0 => spec[..index].to_string(),
+        _ => spec.to_string(),
+    }
+}
+
+fn github_repo(script: &str) -> Option<String> {
+    let start = ["github.com/", "raw.githubusercontent.com/"]
+        .iter()
+        .find_map(|host| script.find(host).map(|index| index + host.len()))?;
+    let mut parts = script[start..].split('/');
+    let (owner, repo) = (parts.next()?, parts.next()?);
+    (!owner.is_empty() && !repo.is_empty()).then(|| format!("{owner}/{repo}"))
+}
+
+pub fn json_field(body: &str, field: &str) -> Option<String> {
+    let key = format!("\"{field}\"");
+    let rest = body[body.find(&key)? + key.len()..].trim_start();
+    let rest = rest.strip_prefix(':')?.trim_start().strip_prefix('"')?;
+    Some(rest[..rest.find('"')?].to_string())
+}
+
+#[cfg(test)]
+#[path = "latest_test.rs"]
+mod tests;
@@ -0,0 +1,74 @@
+use super::*;
+use crate::contracts::{CapabilityPlan, EnvMode};
+
+fn harness(command: &str, args: &[&str]) -> Harness {
+    let args = args.iter().map(|arg| arg.to_string()).collect();
+    Harness {
+        name: "tool".into(),
+        display: "Tool".into(),
+        description: String::new(),
+        binary: "tool".into(),
+        env_mode: EnvMode::None,
+        env: Vec::new(),
+        full_screen: false,
+        project_args: vec![],
+        capabilities: vec![CapabilityPlan {
+            capability: Capability::Download,
+            summary: String::new(),
+            command: CommandPlan::new(command.into(), args),
+        }],
+    }
+}
+
+#[test]
+fn package_managers_map_to_their_registry() {
+    let npm = harness("npm", &["install", "-g", "@openai/codex"]);
+    assert_eq!(source(&npm), Some(Source::Npm("@openai/codex".into())));
+    let tagged = harness("pnpm", &["add", "-g", "opencode-ai@latest"]);
+    assert_eq!(source(&tagged), Some(Source::Npm("opencode-ai".into())));
+    let uv = harness("uv", &["tool", "install", "--force", "aider-chat@latest"]);
+    assert_eq!(source(&uv), Some(Source::PyPi("aider-chat".into())));
+    let pip = harness("pip", &["install", "cursor-agent-tools==1.0"]);
+    assert_eq!(
+        source(&pip),
+        Some(Source::PyPi("cursor-agent-tools".into()))
+    );
+}
+
+#[test]
+fn script_installers_use_github_releases_only_when_a_repo_is_known() {
+    let goose = harness(
+        "sh",
+        &["-c", "curl -fsSL https://github.com/block/goose/releases/download/stable/download_cli.sh | bash"],
+    );
+    assert_eq!(source(&goose), Some(Source::GitHub("block/goose".into())));
+    let eca = harness(
+        "sh",
+        &["-c", "curl -s https://raw.githubusercontent.com/editor-code-assistant/eca/master/install | bash"],
+    );
+    assert_eq!(
+        source(&eca),
+        Some(Source::GitHub("editor-code-assistant/eca".into()))
+    );
+    let claude = harness(
+        "sh",
+        &["-c", "curl -fsSL https://claude.ai/install.sh | bash"],
+    );
+    assert_eq!(source(&claude), None);
+}
+
+#[test]
+fn json_fields_are_read_from_registry_responses() {
+    let pypi = r#"{"info": {"name": "aider-chat", "version": "0.86.1"}, "releases": {}}"#;
+    assert_eq!(json_field(pypi, "version").as_deref(), Some("0.86.1"));
+    let github = r#"{"url":"x","tag_name":"v1.9.0","name":"1.9"}"#;
+    assert_eq!(json_field(github, "tag_name").as_deref(), Some("v1.9.0"));
+    assert_eq!(json_field("{}", "version"), None);
+}
+
+#[test]
+fn lookups_with_malformed_package_names_never_reach_curl() {
+    let timeout = std::time::Duration::from_millis(1);
+    assert_eq!(latest(&Source::PyPi("aider chat".into()), timeout), None);
+    assert_eq!(latest(&Source::GitHub("a\\b/c".into()), timeout), None);
+}
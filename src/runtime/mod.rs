@@ -1,5 +1,18 @@
 mod agent_loop;
+mod latest;
+mod node_manager;
+mod pool;
+mod probe;
 mod runner;
+mod sandbox;
+mod tee;
+mod versions;
 
 pub use agent_loop::{next_step, planned_steps};
+pub use latest::{json_field, latest, source, Source};
+pub use node_manager::{adapt_harnesses, node_manager};
+pub use pool::run_bounded;
+pub use probe::{capture, output};
 pub use runner::run_command;
+pub use sandbox::{detect_runtime, wrap, Sandbox, DEFAULT_IMAGE};
+pub use versions::{compare, parse_version};
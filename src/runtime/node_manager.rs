@@ -0,0 +1,54 @@
+use crate::contracts::{CommandPlan, Harness};
+
+const MANAGERS: [&str; 3] = ["npm", "pnpm", "bun"];
+
+pub fn node_manager<F>(setting: Option<&str>, on_path: F) -> Result<&'static str, String>
+where
+    F: Fn(&str) -> bool,
+{
+    match setting.map(str::trim).filter(|value| !value.is_empty()) {
+        None | Some("auto") => Ok(MANAGERS
+            .into_iter()
+            .find(|manager| on_path(manager))
+            .unwrap_or("npm")),
+        Some(other) => MANAGERS
+            .into_iter()
+            .find(|manager| *manager == other)
+            .ok_or_else(|| {
+                format!("TERMINAL_JARVIS_NODE_PM must be npm, pnpm, bun, or auto; got '{other}'")
+            }),
+    }
+}
+
+pub fn adapt_harnesses(harnesses: &mut [Harness], manager: &str) {
+    for plan in harnesses
+        .iter_mut()
+        .flat_map(|harness| &mut harness.capabilities)
+    {
+        if let Some(command) = adapt(&plan.command, manager) {
+            plan.command = command;
+            plan.summary = plan.summary.replace("npm", manager);
+        }
+    }
+}
+
+fn adapt(command: &CommandPlan, manager: &str) -> Option<CommandPlan> {
+    if manager == "npm" || command.command != "npm" {
+        return None;
+    }
+    let [verb, global, package] = command.args.as_slice() else {
+        return None;
+    };
+    let package = match (verb.as_str(), global.as_str()) {
+        ("install", "-g") => package.clone(),
+        ("update", "-g") if package[1..].contains('@') => package.clone(),
+        ("update", "-g") => format!("{package}@latest"),
+        _ => return None,
+    };
+    let args = vec!["add".to_string(), "-g".to_string(), package];
+    Some(CommandPlan::new(manager.to_string(), args).with_requires(vec![manager.to_string()]))
+}
+
+#[cfg(test)]
+#[path = "node_manager_test.rs"]
+mod tests;
@@ -0,0 +1,49 @@
+use super::*;
+
+fn npm(args: &[&str]) -> CommandPlan {
+    let args = args.iter().map(|arg| arg.to_string()).collect();
+    CommandPlan::new("npm".into(), args).with_requires(vec!["npm".into()])
+}
+
+#[test]
+fn auto_prefers_npm_then_pnpm_then_bun() {
+    let only = |names: &'static [&'static str]| move |name: &str| names.contains(&name);
+    assert_eq!(node_manager(None, only(&["npm", "pnpm", "bun"])), Ok("npm"));
+    assert_eq!(
+        node_manager(Some("auto"), only(&["bun", "pnpm"])),
+        Ok("pnpm")
+    );
+    assert_eq!(node_manager(Some(""), only(&["bun"])), Ok("bun"));
+    assert_eq!(node_manager(None, only(&[])), Ok("npm"));
+}
+
+#[test]
+fn explicit_managers_are_used_even_when_missing() {
+    assert_eq!(node_manager(Some("bun"), |_| false), Ok("bun"));
+    assert_eq!(node_manager(Some(" pnpm "), |_| true), Ok("pnpm"));
+    assert!(node_manager(Some("yarn"), |_| true)
+        .unwrap_err()
+        .contains("npm, pnpm, bun, or auto"));
+}
+
+#[test]
+fn global_installs_and_updates_become_add_commands() {
+    let install = adapt(&npm(&["install", "-g", "@openai/codex"]), "pnpm").unwrap();
+    assert_eq!(install.render(), "pnpm add -g @openai/codex");
+    assert_eq!(install.requires, ["pnpm"]);
+    let update = adapt(&npm(&["update", "-g", "@openai/codex"]), "bun").unwrap();
+    assert_eq!(update.render(), "bun add -g @openai/codex@latest");
+    let pinned = adapt(&npm(&["update", "-g", "opencode-ai@1.2.0"]), "bun").unwrap();
+    assert_eq!(pinned.render(), "bun add -g opencode-ai@1.2.0");
+}
+
+#[test]
+fn other_commands_are_left_alone() {
+    assert_eq!(adapt(&npm(&["install", "-g", "x"]), "npm"), None);
+    assert_eq!(adapt(&npm(&["install", "left-pad"]), "bun"), None);
+    let uv = CommandPlan::new(
+        "uv".into(),
+        vec!["tool".into(), "install".into(), "x".into()],
+    );
+    assert_eq!(adapt(&uv, "pnpm"), None);
+}
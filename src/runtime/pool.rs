@@ -0,0 +1,67 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+pub fn run_bounded<T, R, F>(items: &[T], jobs: usize, task: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    if jobs <= 1 {
+        return items.iter().map(task).collect();
+    }
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<_>>());
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                let result = task(item);
+                results.lock().expect("pool results")[index] = Some(result);
+            });
+        }
+    });
+    results
+        .into_inner()
+        .expect("pool results")
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn in_flight_tasks_never_exceed_the_job_count() {
+        let (running, peak) = (AtomicUsize::new(0), AtomicUsize::new(0));
+        let items = (0..12).collect::<Vec<_>>();
+        let results = run_bounded(&items, 3, |item| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(10));
+            running.fetch_sub(1, Ordering::SeqCst);
+            item * 2
+        });
+        assert_eq!(results, (0..12).map(|item| item * 2).collect::<Vec<_>>());
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+        assert!(peak.load(Ordering::SeqCst) > 1);
+    }
+
+    #[test]
+    fn one_job_runs_in_order_on_the_calling_thread() {
+        let caller = thread::current().id();
+        let results = run_bounded(&[1, 2, 3], 1, |item| (*item, thread::current().id()));
+        assert!(results.iter().all(|(_, id)| *id == caller));
+        assert_eq!(
+            results.iter().map(|(item, _)| *item).collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+    }
+}
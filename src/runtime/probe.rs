@@ -0,0 +1,75 @@
+use crate::contracts::CommandPlan;
+use std::io::Read;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub fn capture(plan: &CommandPlan, timeout: Duration) -> Option<String> {
+    output(plan, timeout)
+        .filter(|(success, _)| *success)
+        .map(|(_, text)| text)
+}
+
+pub fn output(plan: &CommandPlan, timeout: Duration) -> Option<(bool, String)> {
+    let mut child = Command::new(&plan.command)
+        .args(&plan.args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .ok()?;
+    let reader = |pipe: Option<Box<dyn Read + Send>>| {
+        thread::spawn(move || {
+            let mut text = String::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_string(&mut text);
+            }
+            text
+        })
+    };
+    let stdout = reader(child.stdout.take().map(|pipe| Box::new(pipe) as _));
+    let stderr = reader(child.stderr.take().map(|pipe| Box::new(pipe) as _));
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        match child.try_wait().ok()? {
+            Some(status) => break status,
+            None if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+            None => thread::sleep(Duration::from_millis(20)),
+        }
+    };
+    let (stdout, stderr) = (stdout.join().ok()?, stderr.join().ok()?);
+    let text = if stdout.trim().is_empty() {
+        stderr
+    } else {
+        stdout
+    };
+    Some((status.success(), text))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn sh(script: &str) -> CommandPlan {
+        CommandPlan::new("sh".into(), vec!["-c".into(), script.into()])
+    }
+
+    #[test]
+    fn capture_prefers_stdout_and_falls_back_to_stderr() {
+        let long = Duration::from_secs(5);
+        assert_eq!(capture(&sh("echo 1.2.3"), long).as_deref(), Some("1.2.3\n"));
+        assert_eq!(capture(&sh("echo v2 >&2"), long).as_deref(), Some("v2\n"));
+        assert_eq!(capture(&sh("echo nope; exit 3"), long), None);
+    }
+
+    #[test]
+    fn capture_gives_up_after_the_timeout() {
+        let started = Instant::now();
+        assert_eq!(capture(&sh("sleep 5"), Duration::from_millis(100)), None);
+        assert!(started.elapsed() < Duration::from_secs(4));
+    }
+}
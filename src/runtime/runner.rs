@@ -1,18 +1,38 @@
-use crate::contracts::CapabilityPlan;
+use super::tee;
+use crate::contracts::{CapabilityPlan, LaunchOptions};
 use std::io;
 use std::process::{Command, Stdio};
 
-pub fn run_command(plan: &CapabilityPlan, extra: &[String]) -> io::Result<(i32, String)> {
+pub fn run_command(
+    plan: &CapabilityPlan,
+    extra: &[String],
+    options: &LaunchOptions,
+) -> io::Result<(i32, String)> {
     let mut command = Command::new(&plan.command.command);
     command.args(&plan.command.args).args(extra);
-    command.stdout(Stdio::inherit());
+    command.envs(options.env.iter().map(|(key, value)| (key, value)));
+    if let Some(dir) = &options.cwd {
+        command.current_dir(dir);
+    }
+    if options.stdin || options.sandbox {
+        command.stdin(Stdio::inherit());
+    } else {
+        command.stdin(Stdio::null());
+    }
+    command.stdout(match options.output_log {
+        Some(_) => Stdio::piped(),
+        None => Stdio::inherit(),
+    });
+    let logging = options.stderr_log.is_some() || options.output_log.is_some();
+    if options.inherit_stderr && !logging {
+        let status = command.stderr(Stdio::inherit()).status()?;
+        return Ok((status.code().unwrap_or(1), String::new()));
+    }
     command.stderr(Stdio::piped());
-    let output = command.output()?;
-    let code = output.status.code().unwrap_or(1);
-    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    let (code, stderr) = tee::stream(command, options)?;
     if code == 0 {
         Ok((0, String::new()))
     } else {
-        Ok((code, stderr))
+        Ok((code, String::from_utf8_lossy(&stderr).to_string()))
     }
 }
@@ -0,0 +1,83 @@
+use crate::contracts::CommandPlan;
+
+pub const DEFAULT_IMAGE: &str = "terminal-jarvis-tools:latest";
+const RUNTIMES: [&str; 2] = ["docker", "podman"];
+const MOUNT: &str = "/workspace";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sandbox {
+    pub runtime: String,
+    pub image: String,
+    pub workdir: String,
+    pub tty: bool,
+}
+
+pub fn detect_runtime<F>(on_path: F) -> Option<&'static str>
+where
+    F: Fn(&str) -> bool,
+{
+    RUNTIMES.into_iter().find(|runtime| on_path(runtime))
+}
+
+pub fn wrap(
+    sandbox: &Sandbox,
+    command: &CommandPlan,
+    extra: &[String],
+    env: &[String],
+) -> CommandPlan {
+    let interactive = if sandbox.tty { "-it" } else { "-i" };
+    let mut args: Vec<String> = ["run", "--rm", interactive, "-v"]
+        .map(String::from)
+        .to_vec();
+    args.push(format!("{}:{MOUNT}", sandbox.workdir));
+    args.extend(["-w".to_string(), MOUNT.to_string()]);
+    for name in env {
+        args.extend(["-e".to_string(), name.clone()]);
+    }
+    args.push(sandbox.image.clone());
+    args.push(command.command.clone());
+    args.extend(command.args.iter().cloned());
+    args.extend(extra.iter().cloned());
+    CommandPlan::new(sandbox.runtime.clone(), args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sandbox(tty: bool) -> Sandbox {
+        Sandbox {
+            runtime: "podman".into(),
+            image: DEFAULT_IMAGE.into(),
+            workdir: "/home/me/project".into(),
+            tty,
+        }
+    }
+
+    #[test]
+    fn docker_is_preferred_and_podman_is_the_fallback() {
+        assert_eq!(detect_runtime(|_| true), Some("docker"));
+        assert_eq!(detect_runtime(|name| name == "podman"), Some("podman"));
+        assert_eq!(detect_runtime(|_| false), None);
+    }
+
+    #[test]
+    fn wrap_mounts_only_the_workdir_and_names_each_env_var() {
+        let command = CommandPlan::new("claude".into(), vec!["-p".into()]);
+        let env = ["ANTHROPIC_API_KEY".to_string()];
+        let wrapped = wrap(&sandbox(true), &command, &["hi".into()], &env);
+        assert_eq!(wrapped.command, "podman");
+        assert_eq!(
+            wrapped.render(),
+            "podman run --rm -it -v /home/me/project:/workspace -w /workspace -e ANTHROPIC_API_KEY terminal-jarvis-tools:latest claude -p hi"
+        );
+    }
+
+    #[test]
+    fn wrap_drops_the_tty_flag_without_a_terminal() {
+        let command = CommandPlan::new("codex".into(), Vec::new());
+        let wrapped = wrap(&sandbox(false), &command, &[], &[]);
+        assert_eq!(&wrapped.args[..3], ["run", "--rm", "-i"]);
+        assert!(!wrapped.args.iter().any(|arg| arg == "-e"));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::contracts::LaunchOptions;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+const TAIL_LINES: usize = 100;
+
+type Log = Arc<Mutex<File>>;
+
+pub fn stream(mut command: Command, options: &LaunchOptions) -> io::Result<(i32, Vec<u8>)> {
+    let output_log = open(&options.output_log)?;
+    let stderr_log = open(&options.stderr_log)?;
+    let echo = !options.quiet_output;
+    let mut child = command.spawn()?;
+    let stdout = child.stdout.take().map(|pipe| {
+        let logs = output_log.clone().into_iter().collect::<Vec<_>>();
+        thread::spawn(move || copy(pipe, io::stdout(), echo, &logs, false))
+    });
+    let logs = output_log.into_iter().chain(stderr_log).collect::<Vec<_>>();
+    let captured = match child.stderr.take() {
+        Some(stderr) => copy(stderr, io::stderr(), echo, &logs, true),
+        None => Err(io::Error::other("harness stderr is not piped")),
+    };
+    let copied = stdout.map_or(Ok(Vec::new()), |handle| {
+        handle
+            .join()
+            .unwrap_or_else(|_| Err(io::Error::other("stdout copy panicked")))
+    });
+    let status = child.wait()?;
+    let captured = captured?;
+    copied?;
+    Ok((status.code().unwrap_or(1), captured))
+}
+
+fn open(path: &Option<PathBuf>) -> io::Result<Option<Log>> {
+    let file = path.as_ref().map(File::create).transpose()?;
+    Ok(file.map(|file| Arc::new(Mutex::new(file))))
+}
+
+fn copy<R: Read, W: Write>(
+    mut from: R,
+    mut to: W,
+    echo: bool,
+    logs: &[Log],
+    keep: bool,
+) -> io::Result<Vec<u8>> {
+    let mut captured = Vec::new();
+    let mut buffer = [0; 8192];
+    loop {
+        let read = from.read(&mut buffer)?;
+        if read == 0 {
+            return Ok(captured);
+        }
+        let chunk = &buffer[..read];
+        if echo {
+            to.write_all(chunk)?;
+            to.flush()?;
+        }
+        for log in logs {
+            log.lock()
+                .map_err(|_| io::Error::other("log lock poisoned"))?
+                .write_all(chunk)?;
+        }
+        if keep {
+            captured.extend_from_slice(chunk);
+            keep_tail(&mut captured, TAIL_LINES);
+        }
+    }
+}
+
+fn keep_tail(captured: &mut Vec<u8>, lines: usize) {
+    let body = captured.strip_suffix(b"\n").unwrap_or(captured);
+    let cut = body
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, byte)| **byte == b'\n')
+        .nth(lines.saturating_sub(1))
+        .map(|(index, _)| index);
+    if let Some(index) = cut {
+        captured.drain(..=index);
+    }
+}
+
+#[cfg(test)]
+#[path = "tee_test.rs"]
+mod tests;
@@ -0,0 +1,23 @@
+use super::*;
+
+#[test]
+fn tail_keeps_the_last_lines_only() {
+    let mut captured = b"one\ntwo\nthree\n".to_vec();
+    keep_tail(&mut captured, 2);
+    assert_eq!(captured, b"two\nthree\n");
+    let mut partial = b"one\ntwo\nthr".to_vec();
+    keep_tail(&mut partial, 2);
+    assert_eq!(partial, b"two\nthr");
+    let mut short = b"one\n".to_vec();
+    keep_tail(&mut short, 2);
+    assert_eq!(short, b"one\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn unpiped_stderr_is_reported_after_the_child_exits() {
+    let mut command = Command::new("sh");
+    command.args(["-c", "exit 0"]);
+    let error = stream(command, &LaunchOptions::default()).unwrap_err();
+    assert_eq!(error.to_string(), "harness stderr is not piped");
+}
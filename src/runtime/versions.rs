@@ -0,0 +1,80 @@
+use std::cmp::Ordering;
+
+pub fn parse_version(text: &str) -> Option<String> {
+    text.split(|char: char| char.is_whitespace() || "(),;'\"".contains(char))
+        .map(|word| word.trim_start_matches(['v', 'V']).trim_end_matches('.'))
+        .find(|word| {
+            let mut parts = word.split('.');
+            let numeric = |part: Option<&str>| {
+                part.is_some_and(|part| part.starts_with(|char: char| char.is_ascii_digit()))
+            };
+            numeric(parts.next()) && numeric(parts.next())
+        })
+        .map(str::to_string)
+}
+
+pub fn compare(installed: &str, latest: &str) -> Option<Ordering> {
+    let (left, right) = (split(installed)?, split(latest)?);
+    Some(left.0.cmp(&right.0).then_with(|| match (left.1, right.1) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(left), Some(right)) => left.cmp(right),
+    }))
+}
+
+fn split(version: &str) -> Option<(Vec<u64>, Option<&str>)> {
+    let version = version.split('+').next().unwrap_or_default();
+    let (core, pre) = match version.split_once('-') {
+        Some((core, pre)) => (core, Some(pre)),
+        None => (version, None),
+    };
+    let mut numbers = core
+        .split('.')
+        .map(|part| part.parse().ok())
+        .collect::<Option<Vec<u64>>>()?;
+    while numbers.len() > 1 && numbers.last() == Some(&0) {
+        numbers.pop();
+    }
+    Some((numbers, pre))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_are_found_inside_tool_banners() {
+        assert_eq!(
+            parse_version("codex-cli 0.46.0\n").as_deref(),
+            Some("0.46.0")
+        );
+        assert_eq!(parse_version("aider v0.86.1").as_deref(), Some("0.86.1"));
+        assert_eq!(
+            parse_version("1.0.128 (Claude Code)").as_deref(),
+            Some("1.0.128")
+        );
+        assert_eq!(parse_version("goose 2\nbuilt today"), None);
+    }
+
+    #[test]
+    fn numeric_parts_compare_numerically() {
+        assert_eq!(compare("0.9.0", "0.10.0"), Some(Ordering::Less));
+        assert_eq!(compare("1.2", "1.2.0"), Some(Ordering::Equal));
+        assert_eq!(compare("2.0.1", "2.0.0"), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn prereleases_sort_before_their_release() {
+        assert_eq!(compare("1.0.0-beta.2", "1.0.0"), Some(Ordering::Less));
+        assert_eq!(compare("1.0.0", "1.0.0-rc.1"), Some(Ordering::Greater));
+        assert_eq!(compare("1.0.0-alpha", "1.0.0-beta"), Some(Ordering::Less));
+        assert_eq!(compare("1.0.0+build.5", "1.0.0"), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn non_semver_strings_are_not_comparable() {
+        assert_eq!(compare("nightly-2025-01-01", "1.0.0"), None);
+        assert_eq!(compare("1.0.0", "latest"), None);
+    }
+}
@@ -0,0 +1,90 @@
+use std::env;
+
+pub const MAX_ARGS: usize = 256;
+pub const MAX_ARG_BYTES: usize = 64 * 1024;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ArgLimits {
+    pub count: usize,
+    pub bytes: usize,
+}
+
+impl Default for ArgLimits {
+    fn default() -> Self {
+        Self {
+            count: MAX_ARGS,
+            bytes: MAX_ARG_BYTES,
+        }
+    }
+}
+
+impl ArgLimits {
+    pub fn from_env() -> Self {
+        let read = |name: &str, default: usize| {
+            env::var(name)
+                .ok()
+                .and_then(|value| value.trim().parse().ok())
+                .filter(|value| *value > 0)
+                .unwrap_or(default)
+        };
+        Self {
+            count: read("TERMINAL_JARVIS_MAX_ARGS", MAX_ARGS),
+            bytes: read("TERMINAL_JARVIS_MAX_ARG_BYTES", MAX_ARG_BYTES),
+        }
+    }
+
+    pub fn check(&self, args: &[String]) -> Result<(), String> {
+        if args.len() > self.count {
+            return Err(format!(
+                "refusing to launch with {} arguments (limit {}; raise TERMINAL_JARVIS_MAX_ARGS if intended)",
+                args.len(),
+                self.count
+            ));
+        }
+        let bytes = args.iter().map(String::len).sum::<usize>();
+        if bytes > self.bytes {
+            return Err(format!(
+                "refusing to launch with {bytes} bytes of arguments (limit {}; raise TERMINAL_JARVIS_MAX_ARG_BYTES if intended)",
+                self.bytes
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(count: usize, each: usize) -> Vec<String> {
+        vec!["x".repeat(each); count]
+    }
+
+    #[test]
+    fn arguments_at_the_limits_are_accepted() {
+        let limits = ArgLimits {
+            count: 4,
+            bytes: 12,
+        };
+        assert_eq!(limits.check(&args(4, 3)), Ok(()));
+        assert_eq!(limits.check(&[]), Ok(()));
+    }
+
+    #[test]
+    fn arguments_beyond_the_limits_are_rejected() {
+        let limits = ArgLimits {
+            count: 4,
+            bytes: 12,
+        };
+        let error = limits.check(&args(5, 1)).unwrap_err();
+        assert!(error.contains("5 arguments (limit 4"));
+        let error = limits.check(&args(2, 7)).unwrap_err();
+        assert!(error.contains("14 bytes of arguments (limit 12"));
+    }
+
+    #[test]
+    fn defaults_leave_room_for_normal_prompts() {
+        let prompt = vec!["--model".into(), "gpt-5".into(), "x".repeat(8 * 1024)];
+        assert_eq!(ArgLimits::default().check(&prompt), Ok(()));
+    }
+}
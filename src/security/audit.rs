@@ -0,0 +1,53 @@
+use crate::runtime::json_field;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Vulnerability {
+    pub severity: String,
+    pub advisory_url: String,
+    pub module_name: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Warn(Vec<Vulnerability>),
+    Block(Vec<Vulnerability>),
+}
+
+pub fn vulnerabilities(report: &str) -> Vec<Vulnerability> {
+    let mut found: Vec<Vulnerability> = Vec::new();
+    for advisory in report.split("\"source\"").skip(1) {
+        let field = |name| json_field(advisory, name).unwrap_or_default();
+        let vulnerability = Vulnerability {
+            severity: field("severity"),
+            advisory_url: field("url"),
+            module_name: field("name"),
+        };
+        let known = found
+            .iter()
+            .any(|seen| seen.advisory_url == vulnerability.advisory_url);
+        if !vulnerability.advisory_url.is_empty() && !known {
+            found.push(vulnerability);
+        }
+    }
+    found
+}
+
+pub fn verdict(found: Vec<Vulnerability>) -> Verdict {
+    let any = |levels: &[&str]| {
+        found
+            .iter()
+            .any(|vulnerability| levels.contains(&vulnerability.severity.as_str()))
+    };
+    if any(&["critical", "high"]) {
+        Verdict::Block(found)
+    } else if any(&["moderate"]) {
+        Verdict::Warn(found)
+    } else {
+        Verdict::Pass
+    }
+}
+
+#[cfg(test)]
+#[path = "audit_test.rs"]
+mod tests;
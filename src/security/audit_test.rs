@@ -0,0 +1,70 @@
+use super::*;
+
+const REPORT: &str = r#"{
+  "auditReportVersion": 2,
+  "vulnerabilities": {
+    "tar": {
+      "name": "tar",
+      "severity": "high",
+      "via": [
+        {
+          "source": 1097493,
+          "name": "tar",
+          "dependency": "tar",
+          "title": "Arbitrary File Creation",
+          "url": "https://github.com/advisories/GHSA-r628-mhmh-qjhw",
+          "severity": "high",
+          "cvss": {"score": 8.1, "vectorString": "CVSS:3.1"},
+          "range": "<6.1.1"
+        }
+      ]
+    },
+    "semver": {
+      "name": "semver",
+      "severity": "moderate",
+      "via": [
+        {
+          "source": 1096482,
+          "name": "semver",
+          "url": "https://github.com/advisories/GHSA-c2qf-rxjj-qqgw",
+          "severity": "moderate"
+        },
+        "tar"
+      ]
+    }
+  },
+  "metadata": {"vulnerabilities": {"moderate": 1, "high": 1, "critical": 0}}
+}"#;
+
+fn only(severity: &str) -> Vec<Vulnerability> {
+    vec![Vulnerability {
+        severity: severity.into(),
+        advisory_url: "https://github.com/advisories/GHSA-x".into(),
+        module_name: "left-pad".into(),
+    }]
+}
+
+#[test]
+fn advisories_are_read_from_npm_audit_json() {
+    let found = vulnerabilities(REPORT);
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].module_name, "tar");
+    assert_eq!(found[0].severity, "high");
+    assert_eq!(
+        found[1].advisory_url,
+        "https://github.com/advisories/GHSA-c2qf-rxjj-qqgw"
+    );
+    assert!(vulnerabilities("{\"vulnerabilities\": {}}").is_empty());
+}
+
+#[test]
+fn high_and_critical_block_moderate_warns_and_low_passes() {
+    assert!(matches!(
+        verdict(vulnerabilities(REPORT)),
+        Verdict::Block(_)
+    ));
+    assert!(matches!(verdict(only("critical")), Verdict::Block(_)));
+    assert_eq!(verdict(only("moderate")), Verdict::Warn(only("moderate")));
+    assert_eq!(verdict(only("low")), Verdict::Pass);
+    assert_eq!(verdict(Vec::new()), Verdict::Pass);
+}
@@ -0,0 +1,98 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Provider {
+    OpenAi,
+    Anthropic,
+    Google,
+    OpenRouter,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyCheck {
+    pub is_valid: bool,
+    pub reason: String,
+    pub confidence: f32,
+}
+
+impl Provider {
+    pub fn for_env(name: &str) -> Option<Self> {
+        match name {
+            "OPENAI_API_KEY" => Some(Provider::OpenAi),
+            "ANTHROPIC_API_KEY" | "CLAUDE_API_KEY" => Some(Provider::Anthropic),
+            "GEMINI_API_KEY" | "GOOGLE_API_KEY" => Some(Provider::Google),
+            "OPENROUTER_API_KEY" => Some(Provider::OpenRouter),
+            _ => None,
+        }
+    }
+
+    fn rule(self) -> (&'static str, &'static str, fn(&str) -> bool, &'static str) {
+        match self {
+            Provider::OpenAi => (
+                "OpenAI",
+                "sk-",
+                openai_length,
+                "51 characters, or at least 80 for sk-proj-/sk-svcacct- keys",
+            ),
+            Provider::Anthropic => (
+                "Anthropic",
+                "sk-ant-",
+                |key| key.len() >= 90,
+                "at least 90 characters",
+            ),
+            Provider::Google => ("Google", "AIza", |key| key.len() == 39, "39 characters"),
+            Provider::OpenRouter => ("OpenRouter", "sk-or-", |_| true, "any length"),
+        }
+    }
+}
+
+pub fn validate_api_key_format(key: &str, provider: Provider) -> KeyCheck {
+    let (name, prefix, length_ok, length) = provider.rule();
+    if key.is_empty() {
+        return check(false, format!("{name} key is empty"), 0.0);
+    }
+    if !key
+        .chars()
+        .all(|char| char.is_ascii_alphanumeric() || char == '-' || char == '_')
+    {
+        return check(
+            false,
+            format!("{name} key contains whitespace, quotes, or other unexpected characters"),
+            0.0,
+        );
+    }
+    let prefixed = key.starts_with(prefix) && !foreign_prefix(key, provider);
+    match (prefixed, length_ok(key)) {
+        (true, true) => check(true, format!("matches the {name} key format"), 1.0),
+        (true, false) => check(
+            true,
+            format!("{name} prefix matches but keys are usually {length}"),
+            0.5,
+        ),
+        (false, true) => check(false, format!("{name} keys start with '{prefix}'"), 0.5),
+        (false, false) => check(
+            false,
+            format!("{name} keys start with '{prefix}' and are {length}"),
+            0.0,
+        ),
+    }
+}
+
+fn openai_length(key: &str) -> bool {
+    let scoped = key.starts_with("sk-proj-") || key.starts_with("sk-svcacct-");
+    (scoped && key.len() >= 80) || (!scoped && key.len() == 51)
+}
+
+fn foreign_prefix(key: &str, provider: Provider) -> bool {
+    provider == Provider::OpenAi && (key.starts_with("sk-ant-") || key.starts_with("sk-or-"))
+}
+
+fn check(is_valid: bool, reason: String, confidence: f32) -> KeyCheck {
+    KeyCheck {
+        is_valid,
+        reason,
+        confidence,
+    }
+}
+
+#[cfg(test)]
+#[path = "key_format_test.rs"]
+mod tests;
@@ -0,0 +1,67 @@
+use super::*;
+
+fn key(prefix: &str, len: usize) -> String {
+    format!("{prefix}{}", "a".repeat(len - prefix.len()))
+}
+
+#[test]
+fn openai_exact_and_prefix_only_matches() {
+    let exact = validate_api_key_format(&key("sk-", 51), Provider::OpenAi);
+    assert!(exact.is_valid);
+    assert_eq!(exact.confidence, 1.0);
+    for prefix in ["sk-proj-", "sk-svcacct-"] {
+        let scoped = validate_api_key_format(&key(prefix, 164), Provider::OpenAi);
+        assert_eq!(
+            (scoped.is_valid, scoped.confidence),
+            (true, 1.0),
+            "{prefix}"
+        );
+    }
+    let short = validate_api_key_format(&key("sk-proj-", 51), Provider::OpenAi);
+    assert_eq!((short.is_valid, short.confidence), (true, 0.5));
+    assert!(!validate_api_key_format(&key("sk-ant-", 51), Provider::OpenAi).is_valid);
+}
+
+#[test]
+fn anthropic_requires_prefix_and_minimum_length() {
+    assert_eq!(
+        validate_api_key_format(&key("sk-ant-", 108), Provider::Anthropic).confidence,
+        1.0
+    );
+    assert_eq!(
+        validate_api_key_format(&key("sk-ant-", 40), Provider::Anthropic).confidence,
+        0.5
+    );
+    let openai = validate_api_key_format(&key("sk-", 95), Provider::Anthropic);
+    assert!(!openai.is_valid);
+    assert!(openai.reason.contains("'sk-ant-'"));
+}
+
+#[test]
+fn google_and_openrouter_rules() {
+    assert!(validate_api_key_format(&key("AIza", 39), Provider::Google).is_valid);
+    let wrong = validate_api_key_format("not-a-google-key", Provider::Google);
+    assert!(!wrong.is_valid);
+    assert_eq!(wrong.confidence, 0.0);
+    let router = validate_api_key_format(&key("sk-or-v1-", 73), Provider::OpenRouter);
+    assert_eq!((router.is_valid, router.confidence), (true, 1.0));
+}
+
+#[test]
+fn empty_or_pasted_with_whitespace_is_rejected() {
+    assert!(!validate_api_key_format("", Provider::OpenAi).is_valid);
+    let pasted = format!("{}\n", key("sk-", 51));
+    let check = validate_api_key_format(&pasted, Provider::OpenAi);
+    assert!(!check.is_valid);
+    assert!(check.reason.contains("whitespace"));
+}
+
+#[test]
+fn env_names_map_to_providers() {
+    assert_eq!(
+        Provider::for_env("CLAUDE_API_KEY"),
+        Some(Provider::Anthropic)
+    );
+    assert_eq!(Provider::for_env("GOOGLE_API_KEY"), Some(Provider::Google));
+    assert_eq!(Provider::for_env("KILO_API_KEY"), None);
+}
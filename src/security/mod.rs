@@ -1,3 +1,21 @@
+mod arg_limits;
+mod audit;
 mod checks;
+mod key_format;
+mod permissions;
+mod project_values;
+mod redact;
+mod requirements;
+mod root;
+mod url;
 
+pub use arg_limits::{ArgLimits, MAX_ARGS, MAX_ARG_BYTES};
+pub use audit::{verdict, vulnerabilities, Verdict, Vulnerability};
 pub use checks::{command_on_path, missing_env};
+pub use key_format::{validate_api_key_format, KeyCheck, Provider};
+pub use permissions::ownership_hint;
+pub use project_values::{project_args, project_env};
+pub use redact::{looks_secret, redact, redact_args};
+pub use requirements::missing_requirements;
+pub use root::{allow_root, root_allowed, root_refusal, running_as_root};
+pub use url::{validate_url, DEFAULT_URL_ALLOWLIST};
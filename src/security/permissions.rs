@@ -0,0 +1,78 @@
+use std::path::Path;
+
+pub fn ownership_hint(output: &str, installer: &str) -> Option<String> {
+    let is_root = crate::context::environment().is_root;
+    hint_for(output, installer, is_root, owned_by_root)
+}
+
+fn hint_for<F>(output: &str, installer: &str, is_root: bool, owned_by_root: F) -> Option<String>
+where
+    F: Fn(&str) -> bool,
+{
+    let denied = output.contains("EACCES") || output.to_lowercase().contains("permission denied");
+    if !denied || is_root {
+        return None;
+    }
+    let Some(path) = denied_path(output) else {
+        return Some(format!("\n  hint: permission denied while writing tool files; if an earlier install ran with sudo, chown the tool directory back to your user and retry without sudo.{}", user_prefix(installer)));
+    };
+    let owner = if owned_by_root(&path) {
+        " and is owned by root"
+    } else {
+        ""
+    };
+    Some(format!(
+        "\n  hint: `{path}` is not writable by your user{owner}. This usually means an earlier install ran with sudo; run `sudo chown -R \"$(id -un)\" {path}` and retry without sudo.{}",
+        user_prefix(installer)
+    ))
+}
+
+fn user_prefix(installer: &str) -> &'static str {
+    match installer {
+        "npm" => " To keep future global installs out of system directories, run `npm config set prefix ~/.npm-global` and add ~/.npm-global/bin to PATH.",
+        "pnpm" => " To keep future global installs out of system directories, run `pnpm setup` and open a new shell.",
+        "bun" => " bun keeps global installs under ~/.bun (or $BUN_INSTALL), so that directory should belong to your user.",
+        _ => "",
+    }
+}
+
+fn denied_path(output: &str) -> Option<String> {
+    let npm_path = output.lines().find_map(|line| {
+        let (_, path) = line
+            .split_once("error path ")
+            .or_else(|| line.split_once("ERR! path "))?;
+        Some(path.trim().to_string())
+    });
+    let quoted = |open: char, close: char| {
+        output
+            .lines()
+            .filter(|line| line.contains("EACCES") || line.to_lowercase().contains("denied"))
+            .find_map(|line| {
+                let (_, rest) = line.split_once(open)?;
+                rest.split_once(close).map(|(path, _)| path.to_string())
+            })
+    };
+    npm_path
+        .or_else(|| quoted('`', '`'))
+        .or_else(|| quoted('\'', '\''))
+        .filter(|path| path.starts_with('/') || path.starts_with('~'))
+}
+
+#[cfg(unix)]
+fn owned_by_root(path: &str) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    Path::new(path)
+        .ancestors()
+        .find_map(|ancestor| std::fs::metadata(ancestor).ok())
+        .is_some_and(|metadata| metadata.uid() == 0)
+}
+
+#[cfg(not(unix))]
+fn owned_by_root(_path: &str) -> bool {
+    false
+}
+
+#[cfg(test)]
+#[path = "permissions_test.rs"]
+mod tests;
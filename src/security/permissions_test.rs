@@ -0,0 +1,81 @@
+use super::hint_for;
+
+const NPM_10: &str = "npm error code EACCES
+npm error syscall mkdir
+npm error path /usr/local/lib/node_modules/@openai
+npm error errno -13
+npm error Error: EACCES: permission denied, mkdir '/usr/local/lib/node_modules/@openai'
+npm error The operation was rejected by your operating system.
+";
+
+const NPM_8: &str = "npm ERR! code EACCES
+npm ERR! syscall rename
+npm ERR! path /usr/local/lib/node_modules/opencode-ai
+npm ERR! errno -13
+";
+
+const NODE_ONLY: &str =
+    "Error: EACCES: permission denied, open '/home/dev/.npm/_cacache/tmp/1a2b'\n";
+
+const UV: &str = "error: failed to create directory `/home/dev/.local/share/uv/tools/aider-chat`: Permission denied (os error 13)\n";
+
+const UV_NO_PATH: &str =
+    "error: Failed to install executables\n  Caused by: Permission denied (os error 13)\n";
+
+#[test]
+fn npm_errors_name_the_reported_path() {
+    for (fixture, path) in [
+        (NPM_10, "/usr/local/lib/node_modules/@openai"),
+        (NPM_8, "/usr/local/lib/node_modules/opencode-ai"),
+        (NODE_ONLY, "/home/dev/.npm/_cacache/tmp/1a2b"),
+    ] {
+        let hint = hint_for(fixture, "npm", false, |_| false).unwrap();
+        assert!(
+            hint.contains(&format!("`{path}` is not writable")),
+            "{hint}"
+        );
+        assert!(hint.contains(&format!("sudo chown -R \"$(id -un)\" {path}")));
+    }
+}
+
+#[test]
+fn uv_errors_name_the_tool_directory() {
+    let hint = hint_for(UV, "uv", false, |_| false).unwrap();
+    assert!(hint.contains("`/home/dev/.local/share/uv/tools/aider-chat`"));
+    let generic = hint_for(UV_NO_PATH, "uv", false, |_| false).unwrap();
+    assert!(generic.contains("chown the tool directory back"));
+}
+
+#[test]
+fn root_owned_paths_are_called_out() {
+    let hint = hint_for(NPM_8, "npm", false, |path| path.starts_with("/usr/local")).unwrap();
+    assert!(hint.contains("and is owned by root"));
+}
+
+#[test]
+fn no_hint_for_root_or_unrelated_failures() {
+    assert_eq!(hint_for(NPM_10, "npm", true, |_| true), None);
+    assert_eq!(
+        hint_for(
+            "npm error code E404\nnpm error 404 Not Found\n",
+            "npm",
+            false,
+            |_| true
+        ),
+        None
+    );
+}
+
+#[test]
+fn node_managers_suggest_a_user_owned_prefix() {
+    for (installer, fix) in [
+        ("npm", "npm config set prefix ~/.npm-global"),
+        ("pnpm", "pnpm setup"),
+        ("bun", "~/.bun (or $BUN_INSTALL)"),
+    ] {
+        let hint = hint_for(NODE_ONLY, installer, false, |_| false).unwrap();
+        assert!(hint.contains(fix), "{hint}");
+    }
+    let uv = hint_for(UV, "uv", false, |_| false).unwrap();
+    assert!(!uv.contains("prefix") && !uv.contains("setup"));
+}
@@ -0,0 +1,62 @@
+use crate::contracts::Harness;
+
+const SAFE_ARGS: [&str; 2] = ["--model", "--context"];
+const SAFE_ENV: [&str; 7] = [
+    "NO_COLOR",
+    "FORCE_COLOR",
+    "COLORTERM",
+    "TERM",
+    "LANG",
+    "LC_ALL",
+    "TZ",
+];
+const UNSAFE_SUFFIXES: [&str; 13] = [
+    "KEY", "TOKEN", "SECRET", "PASSWORD", "URL", "BASE", "HOST", "ENDPOINT", "PROXY", "HOME",
+    "CONFIG", "PATH", "OPTIONS",
+];
+
+pub fn project_args(harness: &Harness, args: &[String]) -> Result<(), String> {
+    let allowed = |flag: &str| {
+        SAFE_ARGS.contains(&flag) || harness.project_args.iter().any(|arg| arg == flag)
+    };
+    let mut value_expected = false;
+    for arg in args {
+        if std::mem::take(&mut value_expected) && !arg.starts_with('-') {
+            continue;
+        }
+        let (flag, inline) = arg
+            .split_once('=')
+            .map_or((arg.as_str(), false), |(flag, _)| (flag, true));
+        if !arg.starts_with('-') || !allowed(flag) {
+            let mut flags = SAFE_ARGS.map(str::to_string).to_vec();
+            flags.extend(harness.project_args.iter().cloned());
+            return Err(format!(
+                "'{}.args' cannot include {arg}; project files may only set {}",
+                harness.name,
+                flags.join(", ")
+            ));
+        }
+        value_expected = !inline;
+    }
+    Ok(())
+}
+
+pub fn project_env(harness: &Harness, name: &str) -> Result<(), String> {
+    let prefix = format!("{}_", harness.name.to_ascii_uppercase().replace('-', "_"));
+    let namespaced = name.len() > prefix.len() && name.starts_with(&prefix);
+    let unsafe_name = UNSAFE_SUFFIXES.iter().any(|suffix| name.ends_with(suffix))
+        || harness.env.iter().any(|credential| credential == name);
+    if SAFE_ENV.contains(&name) || (namespaced && !unsafe_name) {
+        return Ok(());
+    }
+    Err(format!(
+        "'{}.env' cannot set {name}; project files may only set {prefix}* settings \
+         that are not keys, endpoints, or paths, or {}",
+        harness.name,
+        SAFE_ENV.join(", ")
+    ))
+}
+
+#[cfg(test)]
+#[path = "project_values_test.rs"]
+mod tests;
@@ -0,0 +1,66 @@
+use super::*;
+use crate::catalog;
+use std::path::Path;
+
+fn harness(name: &str) -> Harness {
+    catalog::load(Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/harnesses")))
+        .unwrap()
+        .into_iter()
+        .find(|harness| harness.name == name)
+        .unwrap()
+}
+
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+#[test]
+fn model_context_and_declared_flags_are_allowed() {
+    let claude = harness("claude");
+    assert_eq!(project_args(&claude, &args(&["--model", "sonnet"])), Ok(()));
+    assert_eq!(project_args(&claude, &args(&["--model=sonnet"])), Ok(()));
+    let aider = harness("aider");
+    let read = args(&["--read", "CONVENTIONS.md", "--model", "o3"]);
+    assert_eq!(project_args(&aider, &read), Ok(()));
+}
+
+#[test]
+fn approval_bypasses_and_undeclared_args_are_refused() {
+    let claude = harness("claude");
+    for refused in [
+        &["--dangerously-skip-permissions"][..],
+        &["--model", "sonnet", "--dangerously-skip-permissions"],
+        &["--model=sonnet", "fix"],
+        &["--read", "notes.md"],
+    ] {
+        let error = project_args(&claude, &args(refused)).unwrap_err();
+        assert!(error.contains("'claude.args' cannot include"), "{error}");
+    }
+    let codex = args(&["--dangerously-bypass-approvals-and-sandbox"]);
+    assert!(project_args(&harness("codex"), &codex).is_err());
+}
+
+#[test]
+fn only_harness_settings_and_display_variables_are_allowed() {
+    let aider = harness("aider");
+    for name in ["AIDER_DARK_MODE", "NO_COLOR", "LANG"] {
+        assert_eq!(project_env(&aider, name), Ok(()), "{name}");
+    }
+    for name in [
+        "NODE_OPTIONS",
+        "PYTHONPATH",
+        "BASH_ENV",
+        "OPENAI_BASE_URL",
+        "AIDER_OPENAI_API_BASE",
+        "AIDER_OPENAI_API_KEY",
+        "OPENAI_API_KEY",
+        "PATH",
+        "LD_PRELOAD",
+        "DYLD_INSERT_LIBRARIES",
+        "TERMINAL_JARVIS_GATE",
+        "AIDER_",
+    ] {
+        assert!(project_env(&aider, name).is_err(), "{name}");
+    }
+    assert!(project_env(&harness("codex"), "CODEX_HOME").is_err());
+}
@@ -0,0 +1,69 @@
+const MARKERS: [&str; 6] = ["KEY", "TOKEN", "SECRET", "PASSWORD", "AUTH", "CREDENTIAL"];
+
+pub fn looks_secret(name: &str) -> bool {
+    let upper = name.to_ascii_uppercase();
+    MARKERS.iter().any(|marker| upper.contains(marker))
+}
+
+pub fn redact(value: &str) -> String {
+    match value.chars().count() {
+        0 => "<empty>".to_string(),
+        count => format!("<redacted, {count} chars>"),
+    }
+}
+
+pub fn redact_args(args: &[String]) -> Vec<String> {
+    let mut previous_secret = false;
+    args.iter()
+        .map(|arg| {
+            let redacted = match arg.split_once('=') {
+                _ if previous_secret => redact(arg),
+                Some((name, value)) if looks_secret(name) => format!("{name}={}", redact(value)),
+                _ => arg.clone(),
+            };
+            previous_secret = arg.starts_with('-') && !arg.contains('=') && looks_secret(arg);
+            redacted
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn credential_like_names_are_secret() {
+        assert!(looks_secret("OPENAI_API_KEY"));
+        assert!(looks_secret("gh_token"));
+        assert!(looks_secret("DB_PASSWORD"));
+        assert!(!looks_secret("RUST_LOG"));
+    }
+
+    #[test]
+    fn redaction_keeps_only_the_length() {
+        assert_eq!(redact("sk-abc"), "<redacted, 6 chars>");
+        assert_eq!(redact(""), "<empty>");
+    }
+
+    #[test]
+    fn secret_flag_values_are_redacted_in_both_forms() {
+        let args = [
+            "--api-key",
+            "sk-abc",
+            "--model=gpt",
+            "--token=xyz",
+            "fix it",
+        ]
+        .map(String::from);
+        assert_eq!(
+            redact_args(&args),
+            [
+                "--api-key",
+                "<redacted, 6 chars>",
+                "--model=gpt",
+                "--token=<redacted, 3 chars>",
+                "fix it"
+            ]
+        );
+    }
+}
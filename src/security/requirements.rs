@@ -0,0 +1,71 @@
+use super::command_on_path;
+use crate::contracts::CommandPlan;
+
+pub fn missing_requirements(command: &CommandPlan) -> Vec<String> {
+    missing_with(command, std::env::consts::OS, command_on_path)
+}
+
+fn missing_with<F>(command: &CommandPlan, os: &str, on_path: F) -> Vec<String>
+where
+    F: Fn(&str) -> bool,
+{
+    command
+        .requires
+        .iter()
+        .filter_map(|binary| match binary.split_once('@') {
+            Some((binary, only)) => (only == os).then_some(binary),
+            None => Some(binary.as_str()),
+        })
+        .filter(|binary| !on_path(binary))
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::missing_with;
+    use crate::contracts::CommandPlan;
+
+    fn plan(command: &str, requires: &[&str]) -> CommandPlan {
+        CommandPlan::new(command.to_string(), Vec::new())
+            .with_requires(requires.iter().map(|name| name.to_string()).collect())
+    }
+
+    #[test]
+    fn npm_tool_is_blocked_only_when_npm_is_absent() {
+        let npm = plan("npm", &["npm"]);
+        assert_eq!(missing_with(&npm, "linux", |_| false), ["npm"]);
+        assert!(missing_with(&npm, "linux", |name| name == "npm").is_empty());
+    }
+
+    #[test]
+    fn uv_tool_ignores_missing_npm() {
+        let uv = plan("uv", &["uv"]);
+        assert!(missing_with(&uv, "linux", |name| name == "uv").is_empty());
+        assert_eq!(missing_with(&uv, "linux", |name| name == "npm"), ["uv"]);
+    }
+
+    #[test]
+    fn script_installer_reports_each_missing_prerequisite() {
+        let script = plan("sh", &["curl", "bash"]);
+        assert!(missing_with(&script, "linux", |name| name != "npm").is_empty());
+        assert_eq!(
+            missing_with(&script, "linux", |name| name == "bash"),
+            ["curl"]
+        );
+        assert_eq!(missing_with(&script, "linux", |_| false), ["curl", "bash"]);
+    }
+
+    #[test]
+    fn os_qualified_requirements_only_apply_on_that_os() {
+        let q = plan("sh", &["brew@macos", "curl@linux", "unzip@linux"]);
+        assert_eq!(missing_with(&q, "linux", |_| false), ["curl", "unzip"]);
+        assert_eq!(missing_with(&q, "macos", |_| false), ["brew"]);
+        assert!(missing_with(&q, "macos", |name| name == "brew").is_empty());
+    }
+
+    #[test]
+    fn plans_without_requirements_always_proceed() {
+        assert!(missing_with(&plan("claude", &[]), "linux", |_| false).is_empty());
+    }
+}
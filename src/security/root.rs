@@ -0,0 +1,60 @@
+use crate::contracts::CommandPlan;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ALLOWED: AtomicBool = AtomicBool::new(false);
+
+pub fn allow_root(allowed: bool) -> bool {
+    ALLOWED.swap(allowed, Ordering::Relaxed)
+}
+
+pub fn root_allowed() -> bool {
+    ALLOWED.load(Ordering::Relaxed)
+        || std::env::var("TERMINAL_JARVIS_ALLOW_ROOT").is_ok_and(|value| value == "1")
+}
+
+#[cfg(unix)]
+pub fn running_as_root() -> bool {
+    let from_proc = std::fs::read_to_string("/proc/self/status")
+        .ok()
+        .and_then(|status| effective_uid(&status));
+    from_proc.or_else(id_u) == Some(0)
+}
+
+#[cfg(not(unix))]
+pub fn running_as_root() -> bool {
+    false
+}
+
+#[cfg(unix)]
+fn effective_uid(status: &str) -> Option<u32> {
+    let ids = status.lines().find_map(|line| line.strip_prefix("Uid:"))?;
+    ids.split_whitespace().nth(1)?.parse().ok()
+}
+
+#[cfg(unix)]
+fn id_u() -> Option<u32> {
+    let output = std::process::Command::new("id").arg("-u").output().ok()?;
+    String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+}
+
+pub fn root_refusal(command: &CommandPlan, allowed: bool) -> Option<String> {
+    let rendered = command.render();
+    let words = rendered
+        .split(|char: char| char.is_whitespace() || char == '\'')
+        .collect::<Vec<_>>();
+    let global = words
+        .iter()
+        .any(|word| *word == "-g" || *word == "--global");
+    let manager = ["npm", "pnpm", "bun"]
+        .into_iter()
+        .find(|manager| words.contains(manager));
+    manager.filter(|_| global && !allowed).map(|manager| {
+        format!(
+            "refusing to run `{rendered}` as root: global {manager} installs made with sudo leave root-owned files that break later updates. Re-run as your normal user, or pass --allow-root (or set TERMINAL_JARVIS_ALLOW_ROOT=1) if this is intended, e.g. in a container"
+        )
+    })
+}
+
+#[cfg(test)]
+#[path = "root_test.rs"]
+mod tests;
@@ -0,0 +1,49 @@
+use super::*;
+
+fn plan(command: &str, args: &[&str]) -> CommandPlan {
+    CommandPlan::new(
+        command.into(),
+        args.iter().map(|arg| arg.to_string()).collect(),
+    )
+}
+
+#[cfg(unix)]
+#[test]
+fn effective_uid_is_the_second_uid_field() {
+    let status = "Name:\tterminal-jarvis\nUid:\t1000\t0\t0\t0\nGid:\t1000\t1000\t1000\t1000\n";
+    assert_eq!(effective_uid(status), Some(0));
+    assert_eq!(effective_uid("Uid:\t1000\t1000\t1000\t1000\n"), Some(1000));
+    assert_eq!(effective_uid("Name:\tx\n"), None);
+}
+
+#[test]
+fn node_global_installs_are_refused_unless_allowed() {
+    let install = plan("npm", &["install", "-g", "@openai/codex"]);
+    assert!(root_refusal(&install, false)
+        .unwrap()
+        .contains("--allow-root"));
+    assert_eq!(root_refusal(&install, true), None);
+    let wrapped = plan("sh", &["-c", "npm install --global opencode-ai"]);
+    assert!(root_refusal(&wrapped, false).is_some());
+    for manager in ["pnpm", "bun"] {
+        let add = plan(manager, &["add", "-g", "@openai/codex"]);
+        let refusal = root_refusal(&add, false).unwrap();
+        assert!(refusal.contains(&format!("global {manager} installs")));
+    }
+}
+
+#[test]
+fn other_installers_are_not_refused() {
+    assert_eq!(
+        root_refusal(&plan("uv", &["tool", "install", "aider-chat"]), false),
+        None
+    );
+    assert_eq!(
+        root_refusal(&plan("npm", &["install", "left-pad"]), false),
+        None
+    );
+    assert_eq!(
+        root_refusal(&plan("bun", &["add", "left-pad"]), false),
+        None
+    );
+}
@@ -0,0 +1,83 @@
+pub const DEFAULT_URL_ALLOWLIST: [&str; 3] = ["github.com", "npmjs.com", "crates.io"];
+
+pub fn validate_url(url: &str, allowlist: &[&str]) -> Result<(), String> {
+    let refuse = |reason: &str| Err(format!("blocked URL {url}: {reason}"));
+    if url
+        .chars()
+        .any(|char| char.is_whitespace() || char.is_control() || char == '\\')
+    {
+        return refuse("contains whitespace, control characters, or backslashes");
+    }
+    let Some(rest) = url
+        .get(..8)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("https://"))
+        .map(|_| &url[8..])
+    else {
+        return refuse("only https URLs are allowed");
+    };
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    if authority.contains('@') {
+        return refuse("credentials in URLs are not allowed");
+    }
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => (host, Some(port)),
+        None => (authority, None),
+    };
+    if port.is_some_and(|port| port != "443") {
+        return refuse("only the default https port is allowed");
+    }
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    let allowed = allowlist.iter().any(|domain| {
+        let domain = domain.to_ascii_lowercase();
+        host == domain || host.ends_with(&format!(".{domain}"))
+    });
+    match allowed && !host.is_empty() {
+        true => Ok(()),
+        false => refuse(&format!(
+            "host '{host}' is not in the allowlist ({})",
+            allowlist.join(", ")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(url: &str) -> Result<(), String> {
+        validate_url(url, &DEFAULT_URL_ALLOWLIST)
+    }
+
+    #[test]
+    fn https_urls_on_allowed_domains_pass() {
+        assert_eq!(check("https://github.com/owner/repo"), Ok(()));
+        assert_eq!(check("https://registry.npmjs.com/pkg?x=1"), Ok(()));
+        assert_eq!(check("HTTPS://Crates.IO:443/crates/serde"), Ok(()));
+    }
+
+    #[test]
+    fn other_schemes_and_domains_are_blocked() {
+        assert!(check("http://github.com/x")
+            .unwrap_err()
+            .contains("only https"));
+        assert!(check("file:///etc/passwd").is_err());
+        assert!(check("https://evil.example/x")
+            .unwrap_err()
+            .contains("allowlist"));
+        assert!(check("https://github.com.evil.example/").is_err());
+        assert!(check("https://notgithub.com/").is_err());
+        assert!(check("https://").is_err());
+    }
+
+    #[test]
+    fn userinfo_ports_and_odd_characters_are_blocked() {
+        let userinfo = check("https://github.com@evil.example/").unwrap_err();
+        assert!(userinfo.contains("credentials"));
+        assert!(check("https://user:pw@github.com/").is_err());
+        assert!(check("https://github.com:8443/")
+            .unwrap_err()
+            .contains("port"));
+        assert!(check("https://github.com\\@evil.example/").is_err());
+        assert!(check("https://github.com /x").is_err());
+    }
+}
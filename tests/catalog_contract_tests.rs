@@ -9,14 +9,14 @@ fn load() -> Vec<terminal_jarvis::contracts::Harness> {
 fn expected_names() -> Vec<String> {
     let names = "aider amp claude code codex copilot crush cursor-agent droid \
         eca forge gemini goose hermes jules kilocode letta llxprt nanocoder \
-        ollama openclaw opencode pi qwen vibe";
+        ollama openclaw opencode pi q qwen vibe";
     names.split_whitespace().map(str::to_string).collect()
 }
 
 #[test]
 fn harnesses_expose_every_core_capability() {
     let harnesses = load();
-    assert_eq!(harnesses.len(), 25);
+    assert_eq!(harnesses.len(), 26);
     assert!(catalog::validate(&harnesses).is_empty());
     for harness in harnesses {
         for capability in Capability::ALL {
@@ -75,3 +75,21 @@ fn npm_installs_do_not_use_sudo() {
         assert!(!rendered.contains("sudo"), "{} uses sudo", harness.name);
     }
 }
+
+#[test]
+fn package_manager_commands_declare_their_prerequisite() {
+    for harness in load() {
+        for capability in [Capability::Download, Capability::Update] {
+            let command = &harness.plan(capability).unwrap().command;
+            if ["npm", "uv", "pip"].contains(&command.command.as_str()) {
+                assert_eq!(
+                    command.requires,
+                    std::slice::from_ref(&command.command),
+                    "{} {capability} must require {}",
+                    harness.name,
+                    command.command
+                );
+            }
+        }
+    }
+}
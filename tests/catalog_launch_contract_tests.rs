@@ -0,0 +1,52 @@
+use std::path::Path;
+use terminal_jarvis::catalog;
+use terminal_jarvis::contracts::{Capability, Harness};
+
+fn load() -> Vec<Harness> {
+    catalog::load(Path::new("harnesses")).expect("catalog loads")
+}
+
+#[test]
+fn full_screen_interfaces_are_flagged() {
+    let flagged = load()
+        .into_iter()
+        .filter(|harness| harness.full_screen)
+        .map(|harness| harness.name)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        flagged,
+        ["code", "codex", "crush", "jules", "opencode", "vibe"]
+    );
+}
+
+#[test]
+fn full_screen_flags_cover_the_harness_binary_ui() {
+    for harness in load().into_iter().filter(|harness| harness.full_screen) {
+        let ui = harness.plan(Capability::Ui).unwrap();
+        assert_eq!(ui.command.command, harness.binary, "{}", harness.name);
+    }
+}
+
+#[test]
+fn install_plans_require_their_command_and_piped_shells() {
+    for harness in load() {
+        for capability in [Capability::Download, Capability::Update] {
+            let plan = &harness.plan(capability).unwrap().command;
+            if plan.command == harness.binary {
+                continue;
+            }
+            let script = plan.args.join(" ");
+            let piped = ["bash", "zsh"]
+                .into_iter()
+                .filter(|shell| script.contains(&format!("| {shell}")));
+            let needed = [plan.command.as_str()].into_iter().chain(piped);
+            for program in needed.filter(|program| *program != "sh") {
+                assert!(
+                    plan.requires.iter().any(|name| name == program),
+                    "{} {capability} must require {program}",
+                    harness.name
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+mod common;
+
+#[cfg(unix)]
+#[test]
+fn launches_beyond_the_argument_limit_are_refused() {
+    use std::process::Command;
+
+    let root = common::temp_root("arg-limits");
+    let bin = root.join("bin");
+    common::fake_bin(&bin, "opencode", "echo launched");
+    let path = common::path_with(&bin);
+    let run = |name: &str, limit: &str| {
+        Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+            .args(["--plain", "run", "opencode", "one", "two", "three"])
+            .env("TERMINAL_JARVIS_HOME", root.join("home"))
+            .env("PATH", &path)
+            .env(name, limit)
+            .output()
+            .unwrap()
+    };
+    let stdout =
+        |output: &std::process::Output| String::from_utf8_lossy(&output.stdout).to_string();
+    assert_eq!(stdout(&run("TERMINAL_JARVIS_MAX_ARGS", "3")), "launched\n");
+    let refused = run("TERMINAL_JARVIS_MAX_ARGS", "2");
+    assert!(!refused.status.success());
+    assert!(!stdout(&refused).contains("launched"));
+    assert!(String::from_utf8_lossy(&refused.stderr).contains("3 arguments (limit 2"));
+    assert_eq!(
+        stdout(&run("TERMINAL_JARVIS_MAX_ARG_BYTES", "11")),
+        "launched\n"
+    );
+    let refused = run("TERMINAL_JARVIS_MAX_ARG_BYTES", "10");
+    assert!(String::from_utf8_lossy(&refused.stderr).contains("11 bytes of arguments (limit 10"));
+}
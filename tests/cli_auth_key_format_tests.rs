@@ -0,0 +1,58 @@
+use std::process::{Command, Output};
+
+fn auth_help(harness: &str, key: &str, value: &str) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+        .args(["--plain", "auth", "help", harness])
+        .env(
+            "TERMINAL_JARVIS_HOME",
+            std::env::temp_dir().join("tj-key-format-home"),
+        )
+        .env_remove("OPENCODE_API_KEY")
+        .env_remove("OPENAI_API_KEY")
+        .env(key, value)
+        .output()
+        .expect("terminal-jarvis runs")
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+#[test]
+fn auth_help_flags_a_key_with_the_wrong_provider_prefix() {
+    let output = auth_help("opencode", "OPENAI_API_KEY", "AIzaNotAnOpenAiKey");
+    assert!(output.status.success());
+    let body = stdout(&output);
+    assert!(body.contains("status: ready; check OPENAI_API_KEY: OpenAI keys start with 'sk-'"));
+}
+
+#[test]
+fn auth_help_stays_clean_for_a_well_formed_key() {
+    let key = format!("sk-{}", "a".repeat(48));
+    let output = auth_help("opencode", "OPENAI_API_KEY", &key);
+    assert!(stdout(&output).contains("status: ready\n"));
+}
+
+#[test]
+fn auth_list_masks_values_and_flags_suspect_keys() {
+    let output = Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+        .args(["--plain", "auth", "list"])
+        .env(
+            "TERMINAL_JARVIS_HOME",
+            std::env::temp_dir().join("tj-key-format-home"),
+        )
+        .env("OPENCODE_API_KEY", "oc-1234567890xy")
+        .env("OPENAI_API_KEY", "AIzaWrongProvider99")
+        .output()
+        .expect("terminal-jarvis runs");
+    let body = stdout(&output);
+    assert!(
+        body.contains("opencode OPENCODE_API_KEY=oc-1...xy ok"),
+        "{body}"
+    );
+    assert!(
+        body.contains("opencode OPENAI_API_KEY=AIza...99 warn:"),
+        "{body}"
+    );
+    assert!(!body.contains("1234567890"));
+}
@@ -68,7 +68,7 @@ fn empty_catalog_env_still_uses_embedded_catalog() {
     let cwd = temp_dir();
     let output = tj_empty_catalog(&["list"], &cwd);
     assert!(output.status.success());
-    assert_eq!(stdout(&output).lines().count(), 25);
+    assert_eq!(stdout(&output).lines().count(), 26);
 }
 
 #[test]
@@ -1,6 +1,8 @@
+mod common;
+
 #[cfg(unix)]
 mod unix {
-    use std::fs;
+    use super::common;
     use std::process::{Command, Output};
     use std::sync::atomic::{AtomicUsize, Ordering};
 
@@ -11,7 +13,8 @@ mod unix {
         command
             .arg("--plain")
             .args(args)
-            .env("TERMINAL_JARVIS_HOME", home);
+            .env("TERMINAL_JARVIS_HOME", home)
+            .env("TERMINAL_JARVIS_ALLOW_ROOT", "1");
         if let Some(path) = path {
             command.env("PATH", path);
         }
@@ -19,28 +22,18 @@ mod unix {
     }
 
     fn temp_home() -> String {
-        std::env::temp_dir()
-            .join(format!(
-                "terminal-jarvis-compat-{}-{}",
-                std::process::id(),
-                TEMP_ID.fetch_add(1, Ordering::Relaxed)
-            ))
+        let id = TEMP_ID.fetch_add(1, Ordering::Relaxed);
+        common::temp_root(&format!("compat-{id}"))
             .to_string_lossy()
             .to_string()
     }
 
-    fn fake_bin(name: &str) -> (String, String) {
-        use std::os::unix::fs::PermissionsExt;
-
+    fn fake_bin(names: &[&str]) -> String {
         let dir = std::path::PathBuf::from(temp_home()).join("bin");
-        fs::create_dir_all(&dir).unwrap();
-        let path = dir.join(name);
-        fs::write(&path, "#!/usr/bin/env sh\nprintf '%s\\n' \"$*\"\n").unwrap();
-        let mut permissions = fs::metadata(&path).unwrap().permissions();
-        permissions.set_mode(0o755);
-        fs::set_permissions(&path, permissions).unwrap();
-        let old_path = std::env::var("PATH").unwrap_or_default();
-        (dir.to_string_lossy().to_string(), old_path)
+        for name in names {
+            common::fake_bin(&dir, name, "printf '%s\\n' \"$*\"");
+        }
+        common::path_with(&dir)
     }
 
     fn stdout(output: &Output) -> String {
@@ -74,9 +67,7 @@ mod unix {
     #[test]
     fn install_update_and_active_run_forms_use_catalog_commands() {
         let home = temp_home();
-        let (bin, old_path) = fake_bin("npm");
-        let (agent_bin, _) = fake_bin("opencode");
-        let path = format!("{agent_bin}:{bin}:{old_path}");
+        let path = fake_bin(&["npm", "opencode"]);
 
         assert_eq!(
             stdout(&tj(&["install", "opencode"], &home, Some(&path))),
@@ -0,0 +1,48 @@
+#[cfg(unix)]
+#[test]
+fn completions_print_scripts_and_install_for_the_current_shell() {
+    use std::fs;
+    use std::process::Command;
+
+    let root = std::env::temp_dir().join(format!(
+        "terminal-jarvis-completions-{}",
+        std::process::id()
+    ));
+    let user = root.join("user");
+    fs::create_dir_all(&user).unwrap();
+    let tj = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+            .arg("--plain")
+            .args(args)
+            .env("TERMINAL_JARVIS_HOME", root.join("home"))
+            .env("HOME", &user)
+            .env("SHELL", "/bin/bash")
+            .output()
+            .unwrap()
+    };
+    let bash = String::from_utf8_lossy(&tj(&["completions", "bash"]).stdout).to_string();
+    for word in [
+        "list",
+        "install",
+        "outdated",
+        "completions",
+        "codex",
+        "opencode",
+    ] {
+        assert!(bash.contains(word), "{word}");
+    }
+    let bad = tj(&["completions", "tcsh"]);
+    assert!(!bad.status.success());
+    assert!(String::from_utf8_lossy(&bad.stderr).contains("unsupported shell 'tcsh'"));
+
+    let installed = tj(&["--install-completions"]);
+    assert!(installed.status.success());
+    let script = root.join("home/completions/terminal-jarvis.bash");
+    assert!(fs::metadata(&script).unwrap().len() > 0);
+    let rc = fs::read_to_string(user.join(".bashrc")).unwrap();
+    assert_eq!(rc, format!("source \"{}\"\n", script.display()));
+    assert!(tj(&["completions", "--install", "fish"]).status.success());
+    assert!(user
+        .join(".config/fish/completions/terminal-jarvis.fish")
+        .is_file());
+}
@@ -0,0 +1,82 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn catalog(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!(
+        "terminal-jarvis-validate-{}-{name}",
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&root);
+    copy(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("harnesses/jules"),
+        &root.join("jules"),
+    );
+    root
+}
+
+fn copy(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap() {
+        let path = entry.unwrap().path();
+        let target = to.join(path.file_name().unwrap());
+        if path.is_dir() {
+            copy(&path, &target);
+        } else {
+            fs::copy(&path, &target).unwrap();
+        }
+    }
+}
+
+fn validate(root: &Path) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+        .args(["--plain", "config", "validate"])
+        .arg(root)
+        .env("TERMINAL_JARVIS_HOME", root.join("home"))
+        .output()
+        .expect("terminal-jarvis runs")
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+#[test]
+fn valid_catalog_reports_its_harness_count() {
+    let root = catalog("valid");
+    let output = validate(&root);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout(&output).starts_with("catalog ok: 1 harnesses at "));
+}
+
+#[test]
+fn unknown_fields_and_empty_commands_are_all_reported() {
+    let root = catalog("broken");
+    copy(&root.join("jules"), &root.join("other"));
+    fs::write(
+        root.join("other/index.toml"),
+        "name = \"other\"\ndisplay = \"Other\"\ndescription = \"x\"\nbinary = \"other\"\nenv_mode = \"sometimes\"\nenv = []\n",
+    )
+    .unwrap();
+    let ui = root.join("jules/ui/index.toml");
+    let emptied = fs::read_to_string(&ui)
+        .unwrap()
+        .replace("command = \"jules\"", "command = \"\"");
+    fs::write(&ui, emptied).unwrap();
+    let output = validate(&root);
+    let out = stdout(&output);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(out.contains("unknown env mode 'sometimes'"), "{out}");
+    assert!(
+        out.contains("error: jules:ui has an empty command"),
+        "{out}"
+    );
+    assert!(out.ends_with("catalog invalid: 2 problems\n"), "{out}");
+}
+
+#[test]
+fn missing_catalog_is_reported_without_aborting() {
+    let output = validate(Path::new("/nonexistent/terminal-jarvis-catalog"));
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).contains("error: /nonexistent/terminal-jarvis-catalog:"));
+}
@@ -0,0 +1,27 @@
+mod common;
+
+#[cfg(unix)]
+#[test]
+fn default_args_apply_only_when_no_arguments_are_passed() {
+    use std::process::Command;
+
+    let root = common::temp_root("default-args");
+    let bin = root.join("bin");
+    common::fake_bin(&bin, "q", "echo \"q $*\"");
+    let path = common::path_with(&bin);
+    let tj = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+            .arg("--plain")
+            .args(args)
+            .env("TERMINAL_JARVIS_HOME", root.join("home"))
+            .env("PATH", &path)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        String::from_utf8_lossy(&output.stdout).to_string()
+    };
+    assert_eq!(tj(&["run", "q"]), "q chat\n");
+    assert_eq!(tj(&["q"]), "q chat\n");
+    assert_eq!(tj(&["q", "login"]), "q login\n");
+    assert_eq!(tj(&["run", "q", "ui", "--resume"]), "q --resume\n");
+}
@@ -43,7 +43,7 @@ fn list_outputs_promoted_harnesses() {
     let output = tj(&["list"]);
     assert!(output.status.success());
     let body = stdout(&output);
-    assert_eq!(body.lines().count(), 25);
+    assert_eq!(body.lines().count(), 26);
     assert!(body.contains("codex - OpenAI coding agent CLI"));
     assert!(body.contains("vibe - Minimal CLI coding agent by Mistral AI"));
 }
@@ -0,0 +1,67 @@
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use super::common;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::process::{Command, Output};
+
+    fn copy(from: &Path, to: &Path) {
+        fs::create_dir_all(to).unwrap();
+        for entry in fs::read_dir(from).unwrap() {
+            let path = entry.unwrap().path();
+            let target = to.join(path.file_name().unwrap());
+            if path.is_dir() {
+                copy(&path, &target);
+            } else {
+                fs::copy(&path, &target).unwrap();
+            }
+        }
+    }
+
+    fn setup(name: &str, full_screen: bool) -> (PathBuf, Output) {
+        let root = common::temp_root(&format!("tail-{name}"));
+        let (bin, catalog) = (root.join("bin"), root.join("catalog/opencode"));
+        let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("harnesses/opencode");
+        copy(&source, &catalog);
+        let meta = catalog.join("index.toml");
+        let data = fs::read_to_string(&meta).unwrap() + &format!("full_screen = {full_screen}\n");
+        fs::write(meta, data).unwrap();
+        common::fake_bin(&bin, "opencode", "echo 'unknown flag --bogus' >&2\nexit 2");
+        let output = Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+            .args(["--plain", "run", "opencode"])
+            .env("TERMINAL_JARVIS_CATALOG", root.join("catalog"))
+            .env("TERMINAL_JARVIS_HOME", root.join("home"))
+            .env("PATH", common::path_with(&bin))
+            .env("OPENCODE_API_KEY", "test")
+            .env_remove("TERMINAL_JARVIS_LOG_STDERR")
+            .output()
+            .unwrap();
+        (root.join("home/logs"), output)
+    }
+
+    #[test]
+    fn failing_launch_streams_stderr_and_saves_the_tail() {
+        let (logs, output) = setup("tail", false);
+        assert_eq!(output.status.code(), Some(2));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.starts_with("unknown flag --bogus\n"), "{stderr}");
+        let body = String::from_utf8_lossy(&output.stdout);
+        assert!(body.contains("stderr: unknown flag --bogus"), "{body}");
+        let saved = fs::read_dir(&logs).unwrap().next().unwrap().unwrap().path();
+        assert!(
+            body.ends_with(&format!("  saved: {}\n", saved.display())),
+            "{body}"
+        );
+        assert!(fs::read_to_string(saved).unwrap().contains("exit 2"));
+    }
+
+    #[test]
+    fn full_screen_harnesses_keep_stderr_on_the_terminal() {
+        let (_, output) = setup("full", true);
+        assert_eq!(output.status.code(), Some(2));
+        assert!(String::from_utf8_lossy(&output.stderr).contains("unknown flag --bogus"));
+        assert!(!String::from_utf8_lossy(&output.stdout).contains("unknown flag"));
+    }
+}
@@ -0,0 +1,56 @@
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use super::common;
+    use std::fs;
+    use std::process::{Command, Output};
+
+    const HIGH: &str = r#"{"auditReportVersion":2,"vulnerabilities":{"tar":{"via":[{"source":1,"name":"tar","url":"https://github.com/advisories/GHSA-high","severity":"high"}]}}}"#;
+    const MODERATE: &str = r#"{"auditReportVersion":2,"vulnerabilities":{"semver":{"via":[{"source":2,"name":"semver","url":"https://github.com/advisories/GHSA-moderate","severity":"moderate"}]}}}"#;
+
+    fn install(name: &str, report: &str, allow: bool) -> (String, Output) {
+        let root = common::temp_root(&format!("audit-test-{name}"));
+        let bin = root.join("bin");
+        fs::write(root.join("report.json"), report).unwrap();
+        let record = root.join("calls");
+        let body = format!(
+            "case \"$1\" in audit) cat {};; install) echo \"$@\" >> {};; esac",
+            root.join("report.json").display(),
+            record.display()
+        );
+        common::fake_bin(&bin, "npm", &body);
+        let mut command = Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"));
+        command
+            .args(["--plain", "--allow-root", "install", "codex"])
+            .env("TERMINAL_JARVIS_HOME", root.join("home"))
+            .env("PATH", format!("{}:/usr/bin:/bin", bin.display()))
+            .env_remove("TERMINAL_JARVIS_ALLOW_VULNERABLE");
+        if allow {
+            command.env("TERMINAL_JARVIS_ALLOW_VULNERABLE", "1");
+        }
+        let output = command.output().unwrap();
+        (fs::read_to_string(record).unwrap_or_default(), output)
+    }
+
+    #[test]
+    fn high_severity_advisories_block_the_install() {
+        let (calls, output) = install("high", HIGH, false);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(output.status.code(), Some(2));
+        assert!(stderr.contains("high tar: https://github.com/advisories/GHSA-high"));
+        assert!(stderr.contains("TERMINAL_JARVIS_ALLOW_VULNERABLE=1"));
+        assert!(!calls.contains("install -g"), "{calls}");
+    }
+
+    #[test]
+    fn moderate_advisories_warn_and_the_override_lets_high_through() {
+        let (calls, output) = install("moderate", MODERATE, false);
+        assert!(output.status.success(), "{output:?}");
+        assert!(String::from_utf8_lossy(&output.stderr).contains("moderate semver"));
+        assert!(calls.contains("install -g @openai/codex"));
+        let (calls, output) = install("allowed", HIGH, true);
+        assert!(output.status.success(), "{output:?}");
+        assert!(calls.contains("install -g @openai/codex"));
+    }
+}
@@ -0,0 +1,96 @@
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use super::common;
+    use std::process::{Command, Output};
+
+    fn install(preset: &str, npm_exit: i32) -> Output {
+        install_with(preset, npm_exit, &[])
+    }
+
+    fn install_with(preset: &str, npm_exit: i32, present: &[&str]) -> Output {
+        let root = common::temp_root(&format!(
+            "preset-{}-{npm_exit}-{}",
+            preset.replace(' ', "_"),
+            present.len()
+        ));
+        let bin = root.join("bin");
+        let npm = format!("exit {npm_exit}");
+        let stubs = present.iter().map(|name| (*name, "exit 0".to_string()));
+        for (name, script) in [("curl", "exit 0".to_string()), ("npm", npm)]
+            .into_iter()
+            .chain(stubs)
+        {
+            common::fake_bin(&bin, name, &script);
+        }
+        Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+            .args(["--plain", "--allow-root", "install", "--preset"])
+            .args(preset.split(' '))
+            .env("TERMINAL_JARVIS_HOME", root.join("home"))
+            .env("PATH", format!("{}:/usr/bin:/bin", bin.display()))
+            .output()
+            .unwrap()
+    }
+
+    #[test]
+    fn minimal_preset_installs_claude_and_codex() {
+        let output = install("minimal", 0);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_eq!(output.status.code(), Some(0), "{stdout}");
+        assert_eq!(
+            stdout,
+            "claude: installed\ncodex: installed\npreset minimal: 2/2 installed\n"
+        );
+    }
+
+    #[test]
+    fn failed_npm_install_is_reported_per_harness() {
+        let output = install("minimal", 7);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_eq!(output.status.code(), Some(1));
+        assert!(stdout.contains("claude: installed\n"), "{stdout}");
+        assert!(stdout
+            .contains("codex: failed - harness 'codex' capability 'download' failed with exit 7"));
+        assert!(stdout.ends_with("preset minimal: 1/2 installed\n"));
+    }
+
+    #[test]
+    fn harnesses_already_on_path_are_skipped() {
+        let output = install_with("minimal", 0, &["codex"]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_eq!(output.status.code(), Some(0), "{stdout}");
+        assert_eq!(
+            stdout,
+            "claude: installed\ncodex: already installed\npreset minimal: 1/1 installed, 1 already installed\n"
+        );
+    }
+
+    #[test]
+    fn free_preset_only_selects_harnesses_without_keys() {
+        let output = install("free", 0);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        for name in ["jules", "nanocoder", "ollama"] {
+            assert!(stdout.contains(&format!("{name}: ")), "{stdout}");
+        }
+        assert!(!stdout.contains("claude"));
+    }
+
+    #[test]
+    fn parallel_installs_still_report_in_catalog_order() {
+        let output = install("minimal --parallel 2", 0);
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "claude: installed\ncodex: installed\npreset minimal: 2/2 installed\n"
+        );
+        let bad = install("--parallel 0 minimal", 0);
+        assert!(String::from_utf8_lossy(&bad.stderr).contains("positive number, got '0'"));
+    }
+
+    #[test]
+    fn unknown_preset_is_rejected() {
+        let output = install("everything", 0);
+        assert_eq!(output.status.code(), Some(2));
+        assert!(String::from_utf8_lossy(&output.stderr).contains("expected minimal, full, or free"));
+    }
+}
@@ -0,0 +1,49 @@
+mod common;
+
+#[cfg(unix)]
+#[test]
+fn bare_run_continues_with_the_last_used_harness() {
+    use std::process::Command;
+
+    let root = common::temp_root("recent");
+    let bin = root.join("bin");
+    for name in ["opencode", "codex"] {
+        common::fake_bin(&bin, name, &format!("echo {name} \"$@\""));
+    }
+    let path = common::path_with(&bin);
+    let tj = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+            .arg("--plain")
+            .args(args)
+            .env("TERMINAL_JARVIS_HOME", root.join("home"))
+            .env("PATH", &path)
+            .output()
+            .unwrap()
+    };
+    let first = tj(&["run"]);
+    assert!(!first.status.success());
+    assert!(String::from_utf8_lossy(&first.stderr).contains("no active harness"));
+    assert!(tj(&["run", "codex", "--api-key=sk-123", "fix"])
+        .status
+        .success());
+    assert!(tj(&["run", "opencode"]).status.success());
+    let again = tj(&["run"]);
+    assert!(again.status.success());
+    assert_eq!(String::from_utf8_lossy(&again.stdout), "opencode\n");
+    let notice = String::from_utf8_lossy(&again.stderr).to_string();
+    assert!(notice.contains("continuing with opencode (last used just now)"));
+    let listed = String::from_utf8_lossy(&tj(&["list", "--sort", "recent"]).stdout).to_string();
+    let position = |name: &str| listed.find(&format!("{name} - ")).unwrap();
+    assert!(position("opencode") < position("codex"));
+    let current = String::from_utf8_lossy(&tj(&["current"]).stdout).to_string();
+    assert_eq!(current, "active harness = none\nrecent = opencode, codex\n");
+    let history = String::from_utf8_lossy(&tj(&["history", "--tool", "codex"]).stdout).to_string();
+    assert_eq!(
+        history,
+        "codex headless (just now, 0s, exit 0): --api-key=<redacted, 6 chars> fix\n"
+    );
+    let json =
+        String::from_utf8_lossy(&tj(&["history", "--limit", "1", "--json"]).stdout).to_string();
+    assert!(json.starts_with("[{\"at\":") && json.contains("\"tool\":\"opencode\""));
+    assert!(!json.contains("codex"));
+}
@@ -0,0 +1,89 @@
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use super::common;
+    use std::process::{Command, Output};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static TEMP_ID: AtomicUsize = AtomicUsize::new(0);
+
+    fn temp_dir() -> std::path::PathBuf {
+        common::temp_root(&format!(
+            "launch-{}",
+            TEMP_ID.fetch_add(1, Ordering::Relaxed)
+        ))
+    }
+
+    fn fake_bin(name: &str, script: &str) -> String {
+        let dir = temp_dir().join("bin");
+        common::fake_bin(&dir, name, script);
+        common::path_with(&dir)
+    }
+
+    fn tj(args: &[&str], path: &str) -> Command {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"));
+        command
+            .arg("--plain")
+            .args(args)
+            .env("TERMINAL_JARVIS_HOME", temp_dir())
+            .env("PATH", path);
+        command
+    }
+
+    fn stdout(output: &Output) -> String {
+        String::from_utf8_lossy(&output.stdout).to_string()
+    }
+
+    #[test]
+    fn tool_env_reaches_the_child_and_beats_the_parent_env() {
+        let path = fake_bin("opencode", "printf '%s\\n' \"$TJ_LAUNCH_VALUE\"");
+        let output = tj(
+            &["run", "--tool-env", "TJ_LAUNCH_VALUE=child", "opencode"],
+            &path,
+        )
+        .env("TJ_LAUNCH_VALUE", "parent")
+        .output()
+        .unwrap();
+        assert!(output.status.success());
+        assert_eq!(stdout(&output), "child\n");
+    }
+
+    #[test]
+    fn malformed_tool_env_fails_before_launch() {
+        let path = fake_bin("opencode", "echo launched");
+        let output = tj(&["run", "--tool-env", "oops", "opencode"], &path)
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(2));
+        assert!(!stdout(&output).contains("launched"));
+    }
+
+    #[test]
+    fn stdin_flag_pipes_a_prompt_into_the_harness() {
+        use std::io::Write;
+        use std::process::Stdio;
+
+        let path = fake_bin("opencode", "cat");
+        for (flag, expected) in [(true, "refactor this\n"), (false, "")] {
+            let mut args = vec!["run"];
+            if flag {
+                args.push("--stdin");
+            }
+            args.push("opencode");
+            let mut child = tj(&args, &path)
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .spawn()
+                .unwrap();
+            child
+                .stdin
+                .take()
+                .unwrap()
+                .write_all(b"refactor this\n")
+                .unwrap();
+            let output = child.wait_with_output().unwrap();
+            assert_eq!(stdout(&output), expected, "--stdin={flag}");
+        }
+    }
+}
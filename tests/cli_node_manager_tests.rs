@@ -0,0 +1,50 @@
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use super::common;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process::{Command, Output};
+
+    fn tj(name: &str, manager: &str, args: &[&str]) -> (PathBuf, Output) {
+        let root = common::temp_root(&format!("node-pm-{name}"));
+        let (bin, record) = (root.join("bin"), root.join("calls"));
+        let body = format!("echo pnpm \"$@\" >> {}", record.display());
+        common::fake_bin(&bin, "pnpm", &body);
+        common::fake_bin(&bin, "npm", "exit 1");
+        let output = Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+            .args(["--plain", "--allow-root"])
+            .args(args)
+            .env("TERMINAL_JARVIS_HOME", root.join("home"))
+            .env("TERMINAL_JARVIS_NODE_PM", manager)
+            .env("PATH", common::path_with(&bin))
+            .output()
+            .unwrap();
+        (record, output)
+    }
+
+    #[test]
+    fn pnpm_installs_and_updates_npm_harnesses() {
+        let (record, output) = tj("pnpm", "pnpm", &["install", "codex"]);
+        assert!(output.status.success(), "{output:?}");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("could not run npm audit for @openai/codex"));
+        let (update, output) = tj("pnpm-update", "pnpm", &["update", "codex"]);
+        assert!(output.status.success(), "{output:?}");
+        assert_eq!(
+            fs::read_to_string(record).unwrap() + &fs::read_to_string(update).unwrap(),
+            "pnpm add -g @openai/codex\npnpm bin -g\npnpm add -g @openai/codex@latest\n"
+        );
+    }
+
+    #[test]
+    fn unknown_managers_warn_and_fall_back_to_npm() {
+        let (record, output) = tj("bad", "yarn", &["plan", "codex", "download"]);
+        assert!(output.status.success(), "{output:?}");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("npm, pnpm, bun, or auto; got 'yarn'; using npm"));
+        assert!(String::from_utf8_lossy(&output.stdout).contains("npm install -g @openai/codex"));
+        assert!(!record.exists());
+    }
+}
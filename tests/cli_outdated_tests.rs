@@ -0,0 +1,80 @@
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use super::common;
+    use std::fs;
+    use std::process::{Command, Output};
+
+    fn tj(name: &str, args: &[&str]) -> Output {
+        let root = common::temp_root(&format!("outdated-{name}"));
+        let bin = root.join("bin");
+        let npm = format!(
+            "case \"$1 $2\" in \"view @openai/codex\") echo 0.46.0;; \"view opencode-ai\") echo 2.0.0;; \"update -g\") [ \"$3\" = opencode-ai ] && {{ echo 'opencode update failed' >&2; exit 3; }}; echo \"$*\" >> {calls};; \"view @qwen-code/\"*) exit 1;; view*) echo 0.9.0;; *) echo \"$*\" >> {calls};; esac",
+            calls = root.join("calls").display()
+        );
+        let scripts = [
+            ("codex", "echo codex-cli 0.45.0"),
+            ("opencode", "echo 1.0.0"),
+            ("gemini", "echo 0.9.0"),
+            ("qwen", "echo 0.1.0"),
+            ("npm", npm.as_str()),
+        ];
+        for (tool, body) in scripts {
+            common::fake_bin(&bin, tool, body);
+        }
+        Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+            .args(["--plain", "--allow-root"])
+            .args(args)
+            .env("TERMINAL_JARVIS_HOME", root.join("home"))
+            .env("PATH", format!("{}:/usr/bin:/bin", bin.display()))
+            .output()
+            .unwrap()
+    }
+
+    #[test]
+    fn outdated_compares_installed_tools_against_the_registry() {
+        let output = tj("plain", &["outdated"]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_eq!(output.status.code(), Some(1), "{output:?}");
+        assert!(stdout.contains("codex: 0.45.0 -> 0.46.0 (outdated)\n"));
+        assert!(stdout.contains("gemini: 0.9.0 -> 0.9.0 (current)\n"));
+        assert!(stdout.contains("opencode: 1.0.0 -> 2.0.0 (outdated)\n"));
+        assert!(stdout.ends_with("2 outdated\n"));
+    }
+
+    #[test]
+    fn only_outdated_json_is_scriptable() {
+        let output = tj("json", &["outdated", "--only-outdated", "--json"]);
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "[{\"tool\":\"codex\",\"installed\":\"0.45.0\",\"latest\":\"0.46.0\",\"status\":\"outdated\"},{\"tool\":\"opencode\",\"installed\":\"1.0.0\",\"latest\":\"2.0.0\",\"status\":\"outdated\"}]\n"
+        );
+    }
+
+    #[test]
+    fn update_only_outdated_updates_stale_tools_concurrently_and_reports_each() {
+        let output = tj("update", &["update", "--only-outdated"]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_eq!(output.status.code(), Some(1), "{output:?}");
+        assert!(stdout.contains("codex: 0.45.0 -> 0.46.0 (updated)\n"));
+        assert!(stdout.contains("opencode: 1.0.0 -> 2.0.0 (failed)\n"));
+        assert!(stdout.contains("gemini: 0.9.0 -> 0.9.0 (up to date)\n"));
+        assert!(stdout.contains("qwen: 0.1.0 -> ? (skipped)\n"));
+        assert!(stdout.ends_with("1 updated, 1 up to date, 1 skipped (version unknown)\n"));
+        assert!(String::from_utf8_lossy(&output.stderr).contains("opencode update failed"));
+        let root = std::env::temp_dir().join(format!(
+            "terminal-jarvis-outdated-update-{}",
+            std::process::id()
+        ));
+        let calls = fs::read_to_string(root.join("calls")).unwrap();
+        assert_eq!(calls, "update -g @openai/codex\n");
+    }
+
+    #[test]
+    fn unknown_flags_print_usage() {
+        let output = tj("usage", &["outdated", "--bogus"]);
+        assert_eq!(output.status.code(), Some(2));
+        assert!(String::from_utf8_lossy(&output.stderr).contains("outdated [--only-outdated]"));
+    }
+}
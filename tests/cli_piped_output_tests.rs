@@ -0,0 +1,33 @@
+mod common;
+
+#[cfg(unix)]
+#[test]
+fn piped_install_flow_has_no_cursor_control_or_color_bytes() {
+    use std::process::Command;
+
+    let root = common::temp_root("piped");
+    let bin = root.join("bin");
+    common::fake_bin(&bin, "npm", "echo \"added 1 package\"");
+    common::fake_bin(&bin, "curl", "exit 0");
+    for args in [
+        &["install", "opencode"][..],
+        &["install", "--preset", "minimal"][..],
+        &["list"][..],
+        &["show", "opencode"][..],
+        &["install", "missing"][..],
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+            .args(args)
+            .env("TERMINAL_JARVIS_HOME", root.join("home"))
+            .env("TERMINAL_JARVIS_ALLOW_ROOT", "1")
+            .env("PATH", common::path_with(&bin))
+            .env_remove("NO_COLOR")
+            .env("TERM", "xterm-256color")
+            .output()
+            .unwrap();
+        for stream in [&output.stdout, &output.stderr] {
+            assert!(!stream.contains(&b'\r'), "{args:?} wrote a carriage return");
+            assert!(!stream.contains(&0x1b), "{args:?} wrote an escape sequence");
+        }
+    }
+}
@@ -19,6 +19,7 @@ fn tj(args: &[&str], home: &str) -> Output {
         .args(args)
         .env("TERMINAL_JARVIS_HOME", home)
         .env_remove("COLUMNS")
+        .env_remove("TERM")
         .output()
         .expect("terminal-jarvis runs")
 }
@@ -63,7 +64,7 @@ fn rich_mode_handles_selection_and_plain_mode_stays_script_friendly() {
     assert_table(&tj(&["current"], &home), "Active Harness");
     let plain = tj(&["--plain", "list"], &home);
     assert!(plain.status.success());
-    assert_eq!(stdout(&plain).lines().count(), 25);
+    assert_eq!(stdout(&plain).lines().count(), 26);
     assert!(!stdout(&plain).contains("Available Harnesses"));
     let no_color = tj(&["--no-color", "list"], &home);
     assert_table(&no_color, "Available Harnesses");
@@ -78,3 +79,17 @@ fn headless_tables_wrap_without_losing_their_frame() {
         .lines()
         .all(|line| line.chars().count() <= 100));
 }
+
+#[test]
+fn dumb_terminals_get_plain_output_without_the_flag() {
+    let home = home();
+    let dumb = Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+        .args(["list"])
+        .env("TERMINAL_JARVIS_HOME", &home)
+        .env("TERM", "dumb")
+        .output()
+        .expect("terminal-jarvis runs");
+    let body = stdout(&dumb);
+    assert_eq!(body, stdout(&tj(&["--plain", "list"], &home)));
+    assert!(!body.contains('\x1b') && !body.contains('+'), "{body}");
+}
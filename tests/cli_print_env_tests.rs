@@ -0,0 +1,32 @@
+mod common;
+
+#[cfg(unix)]
+#[test]
+fn print_env_reports_redacted_variables_without_launching() {
+    use std::process::Command;
+
+    let root = common::temp_root("env");
+    let (bin, marker) = (root.join("bin"), root.join("launched"));
+    common::fake_bin(&bin, "opencode", &format!("touch {}", marker.display()));
+    let output = Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+        .args([
+            "--plain",
+            "run",
+            "--print-env",
+            "--tool-env",
+            "RUST_LOG=debug",
+        ])
+        .arg("opencode")
+        .env("TERMINAL_JARVIS_HOME", root.join("home"))
+        .env("PATH", common::path_with(&bin))
+        .env("OPENCODE_API_KEY", "secret")
+        .env_remove("OPENAI_API_KEY")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "OPENCODE_API_KEY = <redacted, 6 chars> (environment)\nOPENAI_API_KEY = - (unset)\nRUST_LOG = debug (--tool-env)\n"
+    );
+    assert!(!marker.exists());
+}
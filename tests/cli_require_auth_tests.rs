@@ -0,0 +1,47 @@
+mod common;
+
+#[cfg(unix)]
+#[test]
+fn require_auth_refuses_launches_without_credentials() {
+    use std::process::Command;
+
+    let root = common::temp_root("require-auth");
+    let bin = root.join("bin");
+    common::fake_bin(&bin, "gemini", "echo launched");
+    let path = common::path_with(&bin);
+    let tj = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+            .arg("--plain")
+            .args(args)
+            .env("TERMINAL_JARVIS_HOME", root.join("home"))
+            .env("PATH", &path)
+            .env_remove("GOOGLE_API_KEY")
+            .env_remove("GEMINI_API_KEY")
+            .output()
+            .unwrap()
+    };
+    let text = |bytes: &[u8]| String::from_utf8_lossy(bytes).to_string();
+
+    let warned = tj(&["run", "gemini"]);
+    assert_eq!(text(&warned.stdout), "launched\n");
+    assert!(text(&warned.stderr).contains("warning: Gemini may stop for interactive sign-in"));
+
+    let refused = tj(&["run", "--require-auth", "gemini"]);
+    assert!(!refused.status.success());
+    assert!(text(&refused.stdout).is_empty());
+    let error = text(&refused.stderr);
+    assert!(
+        error.contains("set one of: GOOGLE_API_KEY, GEMINI_API_KEY"),
+        "{error}"
+    );
+    assert!(error.contains("(--require-auth)"));
+
+    let keyed = tj(&[
+        "run",
+        "--require-auth",
+        "--tool-env",
+        "GEMINI_API_KEY=k",
+        "gemini",
+    ]);
+    assert_eq!(text(&keyed.stdout), "launched\n");
+}
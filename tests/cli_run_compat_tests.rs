@@ -1,5 +1,8 @@
+mod common;
+
 #[cfg(unix)]
 mod unix {
+    use super::common;
     use std::fs;
     use std::process::{Command, Output};
     use std::sync::atomic::{AtomicUsize, Ordering};
@@ -17,28 +20,16 @@ mod unix {
     }
 
     fn temp_home() -> String {
-        std::env::temp_dir()
-            .join(format!(
-                "terminal-jarvis-run-{}-{}",
-                std::process::id(),
-                TEMP_ID.fetch_add(1, Ordering::Relaxed)
-            ))
+        let id = TEMP_ID.fetch_add(1, Ordering::Relaxed);
+        common::temp_root(&format!("run-{id}"))
             .to_string_lossy()
             .to_string()
     }
 
-    fn fake_bin(name: &str) -> (String, String) {
-        use std::os::unix::fs::PermissionsExt;
-
+    fn fake_bin(name: &str) -> String {
         let dir = std::path::PathBuf::from(temp_home()).join("bin");
-        fs::create_dir_all(&dir).unwrap();
-        let path = dir.join(name);
-        fs::write(&path, "#!/usr/bin/env sh\nprintf '%s\\n' \"$*\"\n").unwrap();
-        let mut permissions = fs::metadata(&path).unwrap().permissions();
-        permissions.set_mode(0o755);
-        fs::set_permissions(&path, permissions).unwrap();
-        let old_path = std::env::var("PATH").unwrap_or_default();
-        (dir.to_string_lossy().to_string(), old_path)
+        common::fake_bin(&dir, name, "printf '%s\\n' \"$*\"");
+        common::path_with(&dir)
     }
 
     fn stdout(output: &Output) -> String {
@@ -52,8 +43,7 @@ mod unix {
     #[test]
     fn run_accepts_tool_launch_and_prompt_forms() {
         let home = temp_home();
-        let (bin, old_path) = fake_bin("opencode");
-        let path = format!("{bin}:{old_path}");
+        let path = fake_bin("opencode");
 
         let launch = tj(&["run", "opencode"], &home, &path);
         assert!(launch.status.success());
@@ -67,8 +57,7 @@ mod unix {
     #[test]
     fn direct_tool_invocation_forwards_args_to_ui_command() {
         let home = temp_home();
-        let (bin, old_path) = fake_bin("opencode");
-        let path = format!("{bin}:{old_path}");
+        let path = fake_bin("opencode");
 
         let output = tj(&["opencode", "--help"], &home, &path);
         assert!(output.status.success());
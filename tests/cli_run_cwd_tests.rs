@@ -0,0 +1,23 @@
+mod common;
+
+#[cfg(unix)]
+#[test]
+fn cwd_flag_sets_the_harness_working_directory() {
+    use std::process::Command;
+
+    let root = common::temp_root("cwd");
+    let (bin, project) = (root.join("bin"), root.join("project"));
+    std::fs::create_dir_all(&project).unwrap();
+    common::fake_bin(&bin, "opencode", "pwd -P");
+    let output = Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+        .args(["--plain", "run", "--cwd"])
+        .arg(&project)
+        .arg("opencode")
+        .env("TERMINAL_JARVIS_HOME", root.join("home"))
+        .env("PATH", common::path_with(&bin))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let expected = format!("{}\n", project.canonicalize().unwrap().display());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), expected);
+}
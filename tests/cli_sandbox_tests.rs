@@ -0,0 +1,64 @@
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use super::common;
+    use std::process::{Command, Output};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static TEMP_ID: AtomicUsize = AtomicUsize::new(0);
+
+    fn temp_dir() -> std::path::PathBuf {
+        common::temp_root(&format!(
+            "sandbox-{}",
+            TEMP_ID.fetch_add(1, Ordering::Relaxed)
+        ))
+    }
+
+    fn run_with_docker(script: &str, args: &[&str]) -> Output {
+        let dir = temp_dir().join("bin");
+        common::fake_bin(&dir, "docker", script);
+        Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+            .arg("--plain")
+            .args(args)
+            .env("TERMINAL_JARVIS_HOME", temp_dir())
+            .env("TERMINAL_JARVIS_SANDBOX_IMAGE", "tj-test:1")
+            .env("PATH", common::path_with(&dir))
+            .output()
+            .unwrap()
+    }
+
+    #[test]
+    fn sandbox_wraps_the_harness_in_a_container_run() {
+        let output = run_with_docker(
+            "printf '%s\\n' \"$*\"; exit 3",
+            &[
+                "run",
+                "--sandbox",
+                "--tool-env",
+                "TJ_SANDBOX=1",
+                "opencode",
+                "hi",
+            ],
+        );
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_eq!(output.status.code(), Some(3));
+        assert!(stdout.starts_with("run --rm -i -v "), "{stdout}");
+        assert!(stdout.contains(":/workspace -w /workspace"), "{stdout}");
+        assert!(stdout.contains("-e OPENAI_API_KEY -e TJ_SANDBOX tj-test:1 opencode run hi"));
+    }
+
+    #[test]
+    fn sandbox_explains_tools_missing_from_the_image() {
+        let output = run_with_docker(
+            "echo 'executable file not found' >&2; exit 127",
+            &["run", "--sandbox", "opencode"],
+        );
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_eq!(output.status.code(), Some(127));
+        assert!(
+            stdout.contains("`tj-test:1` does not provide `opencode`"),
+            "{stdout}"
+        );
+    }
+}
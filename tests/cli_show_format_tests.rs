@@ -0,0 +1,63 @@
+use std::process::{Command, Output};
+
+fn tj(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+        .arg("--plain")
+        .args(args)
+        .env(
+            "TERMINAL_JARVIS_HOME",
+            std::env::temp_dir().join(format!("terminal-jarvis-show-{}", std::process::id())),
+        )
+        .output()
+        .expect("terminal-jarvis runs")
+}
+
+fn stdout(args: &[&str]) -> String {
+    let output = tj(args);
+    assert!(output.status.success(), "{args:?}");
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+const INSTALL: &str = "curl -fsSL https://claude.ai/install.sh | bash";
+
+#[test]
+fn text_format_matches_the_default_show_output() {
+    let text = stdout(&["show", "claude", "--format", "text"]);
+    assert_eq!(text, stdout(&["show", "claude"]));
+    assert!(text.contains("claude"));
+    assert!(text.contains(INSTALL));
+}
+
+#[test]
+fn json_format_is_a_flat_object_with_the_install_command() {
+    let json = stdout(&["info", "claude", "--format=json"]);
+    assert!(json.starts_with("{\"name\":\"claude\","), "{json}");
+    assert!(
+        json.contains(&format!("\"install\":\"sh -c '{INSTALL}'\"")),
+        "{json}"
+    );
+    assert!(json.contains("\"env\":[\"ANTHROPIC_API_KEY\""), "{json}");
+    assert!(
+        json.contains("\"install_requires\":[\"curl\",\"bash\"]"),
+        "{json}"
+    );
+    let codex = stdout(&["show", "codex", "--format", "json"]);
+    assert!(codex.contains("\"install_requires\":[\"npm\"]"), "{codex}");
+    assert!(json.trim_end().ends_with('}'));
+}
+
+#[test]
+fn markdown_format_is_a_small_section() {
+    let markdown = stdout(&["show", "claude", "--format", "markdown"]);
+    assert!(markdown.starts_with("## Claude"), "{markdown}");
+    assert!(markdown.contains("(`claude`)"));
+    assert!(markdown.contains(&format!("- Install: `sh -c '{INSTALL}'`")));
+    assert!(markdown.contains("| Capability | Behavior |"));
+}
+
+#[test]
+fn unknown_format_is_rejected() {
+    let output = tj(&["show", "claude", "--format", "yaml"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("expected text, json, or markdown"));
+}
@@ -0,0 +1,52 @@
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use super::common;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process::{Command, Output};
+
+    fn run(name: &str, args: &[&str]) -> (PathBuf, Output) {
+        let root = common::temp_root(&format!("stderr-{name}"));
+        let bin = root.join("bin");
+        common::fake_bin(&bin, "opencode", "echo \"boom: $*\" >&2\necho out");
+        let output = Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+            .arg("--plain")
+            .args(args)
+            .env("TERMINAL_JARVIS_HOME", root.join("home"))
+            .env("PATH", common::path_with(&bin))
+            .env("OPENCODE_API_KEY", "test")
+            .env_remove("TERMINAL_JARVIS_LOG_STDERR")
+            .output()
+            .unwrap();
+        (root.join("home/logs"), output)
+    }
+
+    #[test]
+    fn log_stderr_tees_to_the_terminal_and_a_log_file() {
+        let (logs, output) = run("tee", &["run", "--log-stderr", "opencode", "hi"]);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "out\n");
+        assert!(stderr.starts_with("boom: run hi\n"), "{stderr}");
+        let files = fs::read_dir(&logs).unwrap().collect::<Vec<_>>();
+        assert_eq!(files.len(), 1);
+        let path = files[0].as_ref().unwrap().path();
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        assert!(
+            name.starts_with("opencode.") && name.ends_with(".log"),
+            "{name}"
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "boom: run hi\n");
+        assert!(stderr.contains(&format!("stderr log: {}", path.display())));
+    }
+
+    #[test]
+    fn stderr_is_not_logged_without_the_flag() {
+        let (logs, output) = run("plain", &["run", "opencode"]);
+        assert!(output.status.success());
+        assert!(!logs.exists());
+        assert!(!String::from_utf8_lossy(&output.stderr).contains("stderr log"));
+    }
+}
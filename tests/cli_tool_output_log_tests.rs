@@ -0,0 +1,50 @@
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use super::common;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process::{Command, Output};
+
+    fn run(name: &str, flags: &[&str]) -> (PathBuf, Output) {
+        let root = common::temp_root(&format!("output-{name}"));
+        let (bin, log) = (root.join("bin"), root.join("tool.log"));
+        common::fake_bin(&bin, "opencode", "echo out\necho warn >&2");
+        let output = Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+            .args(["--plain", "run", "--log-tool-output-to"])
+            .arg(&log)
+            .args(flags)
+            .arg("opencode")
+            .env("TERMINAL_JARVIS_HOME", root.join("home"))
+            .env("PATH", common::path_with(&bin))
+            .env("OPENCODE_API_KEY", "test")
+            .output()
+            .unwrap();
+        (log, output)
+    }
+
+    #[test]
+    fn tool_output_is_teed_to_the_file() {
+        let (log, output) = run("tee", &[]);
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "out\n");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.starts_with("warn\n"), "{stderr}");
+        assert!(stderr.contains(&format!("tool output log: {}", log.display())));
+        let logged = fs::read_to_string(&log).unwrap();
+        assert!(
+            logged.contains("out\n") && logged.contains("warn\n"),
+            "{logged}"
+        );
+    }
+
+    #[test]
+    fn quiet_tool_output_only_writes_the_file() {
+        let (log, output) = run("quiet", &["--quiet-tool-output"]);
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "");
+        assert!(!String::from_utf8_lossy(&output.stderr).contains("warn"));
+        assert_eq!(fs::read_to_string(&log).unwrap().lines().count(), 2);
+    }
+}
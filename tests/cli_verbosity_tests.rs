@@ -0,0 +1,40 @@
+mod common;
+
+#[cfg(unix)]
+#[test]
+fn quiet_hides_notices_and_verbose_shows_the_resolved_command() {
+    use std::process::Command;
+
+    let root = common::temp_root("verbosity");
+    let bin = root.join("bin");
+    common::fake_bin(&bin, "codex", "echo codex \"$@\"");
+    let path = common::path_with(&bin);
+    let tj = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+            .arg("--plain")
+            .args(args)
+            .env("TERMINAL_JARVIS_HOME", root.join("home"))
+            .env("PATH", &path)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let text = |bytes: &[u8]| String::from_utf8_lossy(bytes).to_string();
+        (text(&output.stdout), text(&output.stderr))
+    };
+    tj(&["run", "codex"]);
+    let (_, normal) = tj(&["run"]);
+    assert!(normal.contains("continuing with codex"));
+    assert!(!normal.contains("running:"));
+    let (stdout, quiet) = tj(&["--quiet", "run"]);
+    assert_eq!(stdout, "codex\n");
+    assert!(!quiet.contains("continuing with"));
+    let (_, verbose) = tj(&["run", "codex", "--verbose", "fix", "it"]);
+    assert!(
+        !verbose.contains("running:"),
+        "flags after the tool belong to it"
+    );
+    let (_, verbose) = tj(&["--verbose", "run", "codex", "fix"]);
+    assert!(verbose.contains("catalog: "));
+    assert!(verbose.contains("running: codex"));
+    assert!(verbose.contains(" fix"));
+}
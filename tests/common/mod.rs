@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub fn temp_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("terminal-jarvis-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(&root).unwrap();
+    root
+}
+
+#[cfg(unix)]
+pub fn fake_bin(bin: &Path, name: &str, script: &str) -> PathBuf {
+    use std::os::unix::fs::PermissionsExt;
+
+    fs::create_dir_all(bin).unwrap();
+    let path = bin.join(name);
+    fs::write(&path, format!("#!/usr/bin/env sh\n{script}\n")).unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    path
+}
+
+pub fn path_with(bin: &Path) -> String {
+    let old_path = std::env::var("PATH").unwrap_or_default();
+    format!("{}:{old_path}", bin.display())
+}
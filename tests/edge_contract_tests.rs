@@ -17,6 +17,8 @@ fn harness(name: &str, mode: EnvMode, env: Vec<String>) -> Harness {
         binary: "sh".to_string(),
         env_mode: mode,
         env,
+        full_screen: false,
+        project_args: vec![],
         capabilities: Capability::ALL
             .iter()
             .map(|capability| plan(*capability, "Dangerous test plan", "sh"))
@@ -33,9 +35,16 @@ fn validation_reports_contract_errors() {
         binary: String::new(),
         env_mode: EnvMode::None,
         env: vec!["bad-env".to_string()],
+        full_screen: false,
+        project_args: vec![],
         capabilities: vec![
             plan(Capability::Update, "update", "login"),
             plan(Capability::Yolo, "fast mode", "sh"),
+            CapabilityPlan {
+                command: CommandPlan::new("sh".into(), vec![])
+                    .with_requires(vec!["unzip@beos".into()]),
+                ..plan(Capability::Download, "download", "sh")
+            },
         ],
     };
     let errors = catalog::validate(&[bad.clone(), bad]).join("\n");
@@ -46,6 +55,7 @@ fn validation_reports_contract_errors() {
     assert!(errors.contains("missing a core capability"));
     assert!(errors.contains("update command looks interactive"));
     assert!(errors.contains("yolo summary must mention danger"));
+    assert!(errors.contains("bad:download has an invalid requirement 'unzip@beos'"));
 }
 
 #[test]
@@ -0,0 +1,76 @@
+mod common;
+
+#[cfg(unix)]
+#[test]
+fn jarvis_lists_installs_and_launches_against_stub_executables() {
+    use std::fs;
+    use std::path::Path;
+    use terminal_jarvis::{Jarvis, JarvisError, LaunchOptions, Outcome};
+
+    let root = common::temp_root("api");
+    let (bin, record) = (root.join("bin"), root.join("calls"));
+    for name in ["opencode", "npm"] {
+        let body = format!("echo {name} \"$@\" >> {}", record.display());
+        common::fake_bin(&bin, name, &body);
+    }
+    std::env::set_var("PATH", common::path_with(&bin));
+    std::env::set_var("TERMINAL_JARVIS_ALLOW_ROOT", "1");
+
+    let catalog = Path::new(env!("CARGO_MANIFEST_DIR")).join("harnesses");
+    let jarvis = Jarvis::open(&catalog, &root.join("home"), Some("npm")).unwrap();
+    assert!(jarvis.warnings().is_empty());
+    let tools = jarvis.list_tools();
+    assert_eq!(tools.len(), jarvis.harnesses().len());
+    let opencode = tools.iter().find(|tool| tool.name == "opencode").unwrap();
+    assert!(opencode.installed);
+
+    let args = ["fix".to_string(), "tests".to_string()];
+    let launched = jarvis.launch("opencode", &args, LaunchOptions::default());
+    assert_eq!(launched, Ok(Outcome::new(0, String::new())));
+    let installed = jarvis.install("codex").unwrap();
+    assert!(installed.success());
+    let body = installed.output;
+    assert!(body.contains("`codex` is not on PATH"), "{body}");
+    let calls = fs::read_to_string(&record).unwrap();
+    let calls = calls
+        .lines()
+        .filter(|call| !call.contains("--prefix"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        calls,
+        [
+            "opencode fix tests",
+            "npm install -g @openai/codex",
+            "npm prefix -g"
+        ]
+    );
+    assert_eq!(
+        jarvis.launch("nope", &[], LaunchOptions::default()),
+        Err(JarvisError::UnknownHarness("nope".to_string()))
+    );
+    assert_eq!(
+        jarvis.update("nope"),
+        Err(JarvisError::UnknownHarness("nope".to_string()))
+    );
+    let missing = Jarvis::open(&root.join("missing"), &root, None).err();
+    assert!(matches!(missing, Some(JarvisError::Catalog(_))));
+    let yarn = Jarvis::open(&catalog, &root.join("home"), Some("yarn")).unwrap();
+    assert_eq!(yarn.warnings().len(), 1);
+    assert!(
+        yarn.warnings()[0].ends_with("got 'yarn'; using npm"),
+        "{:?}",
+        yarn.warnings()
+    );
+}
+
+#[test]
+fn cli_run_restores_verbosity_for_later_library_calls() {
+    use terminal_jarvis::{Jarvis, Verbosity};
+
+    let home = std::env::temp_dir().join(format!("tj-verbosity-{}", std::process::id()));
+    let catalog = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("harnesses");
+    let code = terminal_jarvis::cli::run(["tj", "-q", "--plain", "list"], &catalog, &home);
+    assert_eq!(code, 0);
+    assert_eq!(Jarvis::set_verbosity(Verbosity::Verbose), Verbosity::Normal);
+    assert_eq!(Jarvis::set_verbosity(Verbosity::Normal), Verbosity::Verbose);
+}
@@ -27,3 +27,17 @@ fn dry_run_reports_npm_update_for_wrapped_distributions() {
         String::from_utf8_lossy(&output.stdout).contains("npm install -g terminal-jarvis@latest")
     );
 }
+
+#[test]
+fn self_update_is_an_alias_for_the_update_flag() {
+    let output = Command::new(env!("CARGO_BIN_EXE_terminal-jarvis"))
+        .args(["--plain", "self-update", "--dry-run"])
+        .env("TERMINAL_JARVIS_DISTRIBUTION", "cargo")
+        .output()
+        .expect("terminal-jarvis runs");
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "terminal-jarvis update plan: cargo install terminal-jarvis\n"
+    );
+}